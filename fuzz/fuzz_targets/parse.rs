@@ -0,0 +1,137 @@
+#![no_main]
+
+use elven_parse::section::SectionType;
+use elven_parse::Elf;
+use libfuzzer_sys::fuzz_target;
+
+/// Exhaust the iterator so every entry gets parsed
+fn drain<I: Iterator>(iter: I) {
+    iter.for_each(drop);
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Malformed files must be rejected with an error and not a panic
+    let elf = match Elf::new(data).parse() {
+        Ok(elf) => elf,
+        Err(_err) => return,
+    };
+    let _ = elf.validate();
+
+    // Walk the headers and the data of every section and segment
+    drain(elf.program_iter());
+    drain(elf.program_iter().rev());
+    for sh in elf.section_iter() {
+        let _ = elf.section_name(sh);
+        let _ = elf.get_section(sh);
+        match sh.sh_type {
+            SectionType::ShtSymTab | SectionType::ShtDynSym => {
+                for sym in elf.symtab_iter(sh) {
+                    let _ = elf.linked_section(&sh)
+                        .map(|strtab| elf.sym_name(sym, &strtab));
+                }
+            }
+            SectionType::ShtRela => drain(elf.rela_iter(sh)),
+            SectionType::ShtRel => drain(elf.rel_iter(sh)),
+            SectionType::ShtRelr => drain(elf.relr_iter(sh)),
+            SectionType::ShtNotes => drain(elf.note_iter(&sh)),
+            SectionType::ShtDynamic => drain(elf.dyn_iter(sh)),
+            _ => {}
+        }
+    }
+    for ph in elf.program_iter() {
+        let _ = elf.get_segment_data(&ph);
+        drain(elf.note_iter_from_segment(&ph));
+        drain(elf.dyn_iter_from_segment(&ph));
+        drain(elf.sections_in_segment(&ph));
+    }
+
+    // Call every query which takes no argument
+    drain(elf.iter_load_segments());
+    drain(elf.iter_note_segments());
+    drain(elf.iter_dynamic_segments());
+    drain(elf.iter_gnu_relro_segments());
+    drain(elf.dynsym_iter().into_iter().flatten());
+    let _ = elf.dynsym_strtab();
+    drain(elf.all_rela_sections());
+    drain(elf.all_rel_sections());
+    drain(elf.all_rela_entries());
+    drain(elf.all_rel_entries());
+    drain(elf.core_dump_notes().into_iter().flatten());
+    let _ = elf.gnu_build_id();
+    let _ = elf.gnu_debuglink();
+    drain(elf.gnu_build_attributes().into_iter().flatten());
+    drain(elf.kernel_module_info().into_iter().flatten());
+    let _ = elf.is_kernel_module();
+    drain(elf.gnu_properties().into_iter().flatten());
+    let _ = elf.tls_template();
+    let _ = elf.plt_entry_size();
+    drain(elf.plt_entries_iter().into_iter().flatten());
+    let _ = elf.text_section();
+    let _ = elf.data_section();
+    let _ = elf.bss_section();
+    let _ = elf.rodata_section();
+    let _ = elf.text_data();
+    let _ = elf.data_data();
+    let _ = elf.bss_data();
+    let _ = elf.rodata_data();
+    let gots = [elf.got_section(), elf.got_plt_section()];
+    for got in gots.into_iter().flatten() {
+        drain(elf.got_entries_iter(&got));
+    }
+    let _ = elf.get_interp();
+    drain(elf.needed_libraries());
+    let _ = elf.soname();
+    let _ = elf.runpath();
+    let _ = elf.rpath();
+    let _ = elf.is_64bit();
+    let _ = elf.is_32bit();
+    let _ = elf.is_little_endian();
+    let _ = elf.is_big_endian();
+    let _ = elf.pointer_size();
+    let _ = elf.is_pie();
+    let _ = elf.is_dynamically_linked();
+    let _ = elf.is_statically_linked();
+    let _ = elf.is_executable();
+    let _ = elf.is_shared_library();
+    let _ = elf.is_relocatable();
+    let _ = elf.is_relocatable_object();
+    let _ = elf.is_core_dump();
+    let _ = elf.has_nx_stack();
+    let _ = elf.has_relro();
+    let _ = elf.has_full_relro();
+    let _ = elf.is_stripped();
+    drain(elf.debug_sections());
+    let _ = elf.debug_info_section();
+    let _ = elf.debug_abbrev_section();
+    let _ = elf.debug_line_section();
+    let _ = elf.debug_str_section();
+    let _ = elf.has_debug_info();
+    let _ = elf.gnu_hash_section();
+    let _ = elf.sysv_hash_section();
+    let _ = elf.gnu_hash_table();
+    let _ = elf.sysv_hash_table();
+    if let Some(hdr) = elf.eh_frame_hdr() {
+        let _ = hdr.find_fde(elf.file_header.e_entry);
+    }
+    let _ = elf.min_load_address();
+    let _ = elf.max_load_address();
+    let _ = elf.image_size();
+    let _ = elf.total_load_size();
+    let _ = elf.entry_point_segment();
+    let _ = elf.entry_point_section();
+    drain(elf.overlapping_sections());
+    drain(elf.overlapping_segments());
+    let _ = elf.has_overlapping_sections();
+    let _ = elf.has_overlapping_segments();
+    drain(elf.ifunc_symbols());
+    drain(elf.all_symbols());
+    drain(elf.exported_symbols());
+    drain(elf.imported_symbols());
+    drain(elf.static_pie_reloc_addresses(0));
+    let _ = elf.find_symbol("main");
+    let _ = elf.find_symbol_by_address(elf.file_header.e_entry);
+    let index = elf.build_section_index();
+    let _ = elf.find_section_fast(".text", &index);
+    let lookup = elf.fastest_symbol_lookup();
+    let _ = lookup.map(|lookup| lookup.lookup("main"));
+});
@@ -0,0 +1,317 @@
+use crate::Result;
+use crate::utils::{checked_table_len, saturating_usize, Integer};
+use crate::file::{ElfData, ElfClass};
+use crate::section::SectionHeader;
+
+/// `Flags` value to process all the relocations before transferring control
+pub const DF_BIND_NOW: u64 = 0x00000008;
+
+/// `Flags1` value to process all the relocations before transferring control
+pub const DF_1_NOW: u64 = 0x00000001;
+/// `Flags1` value marking a position independent executable
+pub const DF_1_PIE: u64 = 0x08000000;
+
+/// Dynamic section entry which holds a tag and its value or address
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DynEnt {
+    /// Identifies how `d_val` should be interpreted
+    pub d_tag: DynTag,
+    /// Integer value or virtual address depending on the `d_tag`
+    pub d_val: u64,
+}
+
+/// Enum to identify dynamic entry tags
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DynTag {
+    None,
+    /// Marks the end of the dynamic section
+    Null,
+    /// String table offset of a needed library name
+    Needed,
+    /// Size in bytes of the PLT relocations
+    PltRelSz,
+    /// Address of the PLT or GOT
+    PltGot,
+    /// Address of the symbol hash table
+    Hash,
+    /// Address of the dynamic string table
+    StrTab,
+    /// Address of the dynamic symbol table
+    SymTab,
+    /// Address of the relocations with addends
+    Rela,
+    /// Total size in bytes of the relocations with addends
+    RelaSz,
+    /// Size in bytes of a relocation with an addend
+    RelaEnt,
+    /// Size in bytes of the dynamic string table
+    StrSz,
+    /// Size in bytes of a symbol table entry
+    SymEnt,
+    /// Address of the initialization function
+    Init,
+    /// Address of the termination function
+    Fini,
+    /// String table offset of the shared object name
+    SoName,
+    /// String table offset of the library search path (deprecated)
+    RPath,
+    /// Start the symbol search from the shared object itself
+    Symbolic,
+    /// Address of the relocations without addends
+    Rel,
+    /// Total size in bytes of the relocations without addends
+    RelSz,
+    /// Size in bytes of a relocation without an addend
+    RelEnt,
+    /// Type of the relocations used by the PLT
+    PltRel,
+    /// Reserved for the debugger
+    Debug,
+    /// Relocations might modify a non-writable segment
+    TextRel,
+    /// Address of the PLT relocations
+    JmpRel,
+    /// Process all the relocations before transferring control
+    BindNow,
+    /// Address of the array of constructors
+    InitArray,
+    /// Address of the array of destructors
+    FiniArray,
+    /// Size in bytes of the array of constructors
+    InitArraySz,
+    /// Size in bytes of the array of destructors
+    FiniArraySz,
+    /// String table offset of the library search path
+    RunPath,
+    /// Flags for the object being loaded
+    Flags,
+    /// Address of the array of preconstructors
+    PreInitArray,
+    /// Size in bytes of the array of preconstructors
+    PreInitArraySz,
+    /// Address of the extended section indices
+    SymTabShndx,
+    /// Total size in bytes of the RELR relocations
+    RelrSz,
+    /// Address of the RELR relocations
+    Relr,
+    /// Size in bytes of a RELR relocation
+    RelrEnt,
+    /// Address of the GNU style hash table
+    GnuHash,
+    /// Address of the symbol version table
+    VerSym,
+    /// Number of relative relocations with addends
+    RelaCount,
+    /// Number of relative relocations without addends
+    RelCount,
+    /// State flags for the object being loaded
+    Flags1,
+    /// Address of the version definition table
+    VerDef,
+    /// Number of the version definitions
+    VerDefNum,
+    /// Address of the version dependency table
+    VerNeed,
+    /// Number of the version dependencies
+    VerNeedNum,
+    /// OS specific tags
+    Os,
+    /// Processor specific tags
+    Proc,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `dyn_iter()` function to be called
+/// on the elf struct
+/// The iteration stops at the first `DynTag::Null` entry
+#[derive(Debug, Clone, Copy)]
+pub struct DynIterator<'a> {
+    /// Owned dynamic entry struct to address
+    dynamic: DynEnt,
+    /// Dynamic section offset in the elf file
+    offset: usize,
+    /// Dynamic entry size
+    entsize: usize,
+    /// Number of dynamic entries also used as
+    /// the index of the iteration
+    dynnum: usize,
+    /// Elf class used for parsing
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+impl From<u64> for DynTag {
+    fn from(value: u64) -> Self {
+        match value {
+            0x00000000 => DynTag::Null,
+            0x00000001 => DynTag::Needed,
+            0x00000002 => DynTag::PltRelSz,
+            0x00000003 => DynTag::PltGot,
+            0x00000004 => DynTag::Hash,
+            0x00000005 => DynTag::StrTab,
+            0x00000006 => DynTag::SymTab,
+            0x00000007 => DynTag::Rela,
+            0x00000008 => DynTag::RelaSz,
+            0x00000009 => DynTag::RelaEnt,
+            0x0000000a => DynTag::StrSz,
+            0x0000000b => DynTag::SymEnt,
+            0x0000000c => DynTag::Init,
+            0x0000000d => DynTag::Fini,
+            0x0000000e => DynTag::SoName,
+            0x0000000f => DynTag::RPath,
+            0x00000010 => DynTag::Symbolic,
+            0x00000011 => DynTag::Rel,
+            0x00000012 => DynTag::RelSz,
+            0x00000013 => DynTag::RelEnt,
+            0x00000014 => DynTag::PltRel,
+            0x00000015 => DynTag::Debug,
+            0x00000016 => DynTag::TextRel,
+            0x00000017 => DynTag::JmpRel,
+            0x00000018 => DynTag::BindNow,
+            0x00000019 => DynTag::InitArray,
+            0x0000001a => DynTag::FiniArray,
+            0x0000001b => DynTag::InitArraySz,
+            0x0000001c => DynTag::FiniArraySz,
+            0x0000001d => DynTag::RunPath,
+            0x0000001e => DynTag::Flags,
+            0x00000020 => DynTag::PreInitArray,
+            0x00000021 => DynTag::PreInitArraySz,
+            0x00000022 => DynTag::SymTabShndx,
+            0x00000023 => DynTag::RelrSz,
+            0x00000024 => DynTag::Relr,
+            0x00000025 => DynTag::RelrEnt,
+            0x6ffffef5 => DynTag::GnuHash,
+            0x6ffffff0 => DynTag::VerSym,
+            0x6ffffff9 => DynTag::RelaCount,
+            0x6ffffffa => DynTag::RelCount,
+            0x6ffffffb => DynTag::Flags1,
+            0x6ffffffc => DynTag::VerDef,
+            0x6ffffffd => DynTag::VerDefNum,
+            0x6ffffffe => DynTag::VerNeed,
+            0x6fffffff => DynTag::VerNeedNum,
+            0x60000000..=0x6fffffff => DynTag::Os,
+            0x70000000..=0x7fffffff => DynTag::Proc,
+            _ => DynTag::None,
+        }
+    }
+}
+
+impl Default for DynEnt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DynEnt {
+    /// The default `DynEnt` constructor
+    pub fn new() -> Self {
+        DynEnt {
+            d_tag: DynTag::None,
+            d_val: 0,
+        }
+    }
+
+    /// Parse the dynamic entry and populate the fields
+    pub fn parse(
+        mut self,
+        elf: &[u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<Self> {
+        // Branch and parse according to the elf architecture class
+        if class == ElfClass::Class32 {
+            // Get the dynamic entry tag
+            let tag = u32::endian_parse(0x00..0x04, elf, &data)? as u64;
+            self.d_tag = DynTag::from(tag);
+
+            // Get the dynamic entry value or address
+            self.d_val = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
+        } else if class == ElfClass::Class64 {
+            // Get the dynamic entry tag
+            let tag = u64::endian_parse(0x00..0x08, elf, &data)?;
+            self.d_tag = DynTag::from(tag);
+
+            // Get the dynamic entry value or address
+            self.d_val = u64::endian_parse(0x08..0x10, elf, &data)?;
+        }
+
+        Ok(self)
+    }
+}
+
+impl<'a> Iterator for DynIterator<'a> {
+    type Item = DynEnt;
+    fn next(&mut self) -> Option<Self::Item> {
+        // If the number of dynamic entries is zero then abort the iterator
+        if self.dynnum == 0 {
+            None
+        } else {
+            // Parse the dynamic entry into the struct
+            let end = self.offset.checked_add(self.entsize)?;
+            self.dynamic = self
+                .dynamic
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
+                .ok()?;
+
+            // Calculate the next offset for the next dynamic entry
+            self.offset = end;
+
+            // Subtract one from the number of the dynamic entries
+            self.dynnum -= 1;
+
+            // The dynamic section ends at the first null entry
+            if self.dynamic.d_tag == DynTag::Null {
+                self.dynnum = 0;
+                return None;
+            }
+
+            Some(self.dynamic)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The iteration can end early at a null entry
+        (0, Some(self.dynnum))
+    }
+}
+
+impl<'a> DynIterator<'a> {
+    pub fn new(
+        sh: SectionHeader,
+        class: ElfClass,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        // Construct a empty dynamic entry for the dynamic iterator
+        let dynamic = DynEnt::new();
+
+        // Sections without a fixed entry size can not be iterated
+        let dynnum = sh.sh_size.checked_div(sh.sh_entsize).unwrap_or(0);
+        let offset = saturating_usize(sh.sh_offset);
+        let entsize = saturating_usize(sh.sh_entsize);
+
+        // Only the entries inside the file are counted for the upper bound
+        let dynnum = checked_table_len(
+            elf,
+            offset,
+            entsize,
+            saturating_usize(dynnum),
+            |ent| dynamic.parse(ent, class, data),
+        );
+
+        DynIterator {
+            dynamic,
+            offset,
+            entsize,
+            dynnum,
+            class,
+            data,
+            elf,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::Result;
+use crate::Error;
+use crate::utils::Integer;
+use crate::file::{ElfData, ElfClass};
+
+/// Pointer encodings of the `.eh_frame_hdr` fields from the LSB
+/// The low nibble is the value format and the high one how it is applied
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
+/// Unwind table header stored in the `.eh_frame_hdr` section
+/// It points to `.eh_frame` and holds a table of the FDE addresses sorted
+/// by the first address they cover
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrameHdr<'a> {
+    /// Version of the header which must be 1
+    pub version: u8,
+    /// Encoding of the `eh_frame_ptr` field
+    pub eh_frame_ptr_enc: u8,
+    /// Encoding of the `fde_count` field
+    pub fde_count_enc: u8,
+    /// Encoding of the table entries
+    pub table_enc: u8,
+    /// Virtual address of the `.eh_frame` section
+    pub eh_frame_ptr: u64,
+    /// Number of entries in the table
+    pub fde_count: u64,
+    /// Virtual address of the header used by the relative encodings
+    addr: u64,
+    /// Offset of the table in the header data
+    table: usize,
+    /// Section data including the header
+    data: &'a [u8],
+    /// Elf class used for the `DW_EH_PE_ABSPTR` size
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    e_data: ElfData,
+}
+
+impl<'a> EhFrameHdr<'a> {
+    /// Parse the header from the `.eh_frame_hdr` data loaded at `addr`
+    pub fn parse(
+        data: &'a [u8],
+        addr: u64,
+        class: ElfClass,
+        e_data: ElfData,
+    ) -> Result<Self> {
+        let fields = data.get(0x00..0x04).ok_or(Error::TruncatedElf)?;
+        let mut hdr = EhFrameHdr {
+            version: fields[0],
+            eh_frame_ptr_enc: fields[1],
+            fde_count_enc: fields[2],
+            table_enc: fields[3],
+            eh_frame_ptr: 0,
+            fde_count: 0,
+            addr,
+            table: 0,
+            data,
+            class,
+            e_data,
+        };
+
+        // Only the first version of the header is defined
+        if hdr.version != 1 {
+            return Err(Error::BadElf);
+        }
+
+        // Decode the pointer to `.eh_frame`
+        let (eh_frame_ptr, offset) = hdr
+            .read_encoded(0x04, hdr.eh_frame_ptr_enc)
+            .ok_or(Error::BadElf)?;
+        hdr.eh_frame_ptr = eh_frame_ptr;
+
+        // Without a count or a fixed entry size the table can not be
+        // searched so it is left empty
+        if hdr.fde_count_enc != DW_EH_PE_OMIT && hdr.entry_size().is_some() {
+            let (fde_count, offset) = hdr
+                .read_encoded(offset, hdr.fde_count_enc)
+                .ok_or(Error::BadElf)?;
+            hdr.fde_count = fde_count;
+            hdr.table = offset;
+        }
+
+        Ok(hdr)
+    }
+
+    /// Returns the `(initial_location, fde_address)` virtual addresses of
+    /// the table entry at `ndx`
+    #[must_use]
+    pub fn entry(&self, ndx: u64) -> Option<(u64, u64)> {
+        if ndx >= self.fde_count {
+            return None;
+        }
+        let size = self.entry_size()?;
+        let offset = usize::try_from(ndx).ok()?.checked_mul(size * 2)?;
+        let offset = self.table.checked_add(offset)?;
+        let (location, offset) = self.read_encoded(offset, self.table_enc)?;
+        let (fde, _) = self.read_encoded(offset, self.table_enc)?;
+        Some((location, fde))
+    }
+
+    /// Binary search the table for the FDE covering `pc` and returns its
+    /// offset from the start of `.eh_frame`
+    /// The FDE is the last one starting at or before `pc` so its range must
+    /// still be checked in `.eh_frame`
+    #[must_use]
+    pub fn find_fde(&self, pc: u64) -> Option<u64> {
+        // Count the entries starting at or before `pc`
+        let mut low = 0;
+        let mut high = self.fde_count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.entry(mid)?.0 <= pc {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        let (_, fde) = self.entry(low.checked_sub(1)?)?;
+        fde.checked_sub(self.eh_frame_ptr)
+    }
+
+    /// Returns the size of a value of the table encoding or `None` for the
+    /// variable size formats which can not be binary searched
+    fn entry_size(&self) -> Option<usize> {
+        if self.table_enc == DW_EH_PE_OMIT {
+            return None;
+        }
+        match self.table_enc & 0x0f {
+            DW_EH_PE_ABSPTR => Some(self.absptr_size()),
+            DW_EH_PE_UDATA2 | DW_EH_PE_SDATA2 => Some(0x02),
+            DW_EH_PE_UDATA4 | DW_EH_PE_SDATA4 => Some(0x04),
+            DW_EH_PE_UDATA8 | DW_EH_PE_SDATA8 => Some(0x08),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of an address for the elf class
+    fn absptr_size(&self) -> usize {
+        if self.class == ElfClass::Class32 { 0x04 } else { 0x08 }
+    }
+
+    /// Decode the value with the pointer encoding `enc` at `offset` in the
+    /// header and returns it with the offset following it
+    /// The indirect and the text or function relative encodings need the
+    /// loaded image so they are not supported
+    fn read_encoded(&self, offset: usize, enc: u8) -> Option<(u64, usize)> {
+        if enc & DW_EH_PE_INDIRECT != 0 {
+            return None;
+        }
+
+        let ptr = self.absptr_size();
+        let (value, size) = match enc & 0x0f {
+            DW_EH_PE_ULEB128 => read_leb128(self.data.get(offset..)?, false)?,
+            DW_EH_PE_SLEB128 => read_leb128(self.data.get(offset..)?, true)?,
+            DW_EH_PE_ABSPTR => (self.read_fixed(offset, ptr)?, ptr),
+            DW_EH_PE_UDATA2 => (self.read_fixed(offset, 0x02)?, 0x02),
+            DW_EH_PE_UDATA4 => (self.read_fixed(offset, 0x04)?, 0x04),
+            DW_EH_PE_UDATA8 => (self.read_fixed(offset, 0x08)?, 0x08),
+            DW_EH_PE_SDATA2 => {
+                (self.read_fixed(offset, 0x02)? as i16 as u64, 0x02)
+            }
+            DW_EH_PE_SDATA4 => {
+                (self.read_fixed(offset, 0x04)? as i32 as u64, 0x04)
+            }
+            DW_EH_PE_SDATA8 => (self.read_fixed(offset, 0x08)?, 0x08),
+            _ => return None,
+        };
+
+        // Apply the value relative to its base address
+        let value = match enc & 0x70 {
+            DW_EH_PE_ABSPTR => value,
+            DW_EH_PE_PCREL => {
+                self.addr.wrapping_add(offset as u64).wrapping_add(value)
+            }
+            DW_EH_PE_DATAREL => self.addr.wrapping_add(value),
+            _ => return None,
+        };
+
+        Some((value, offset.checked_add(size)?))
+    }
+
+    /// Returns the unsigned `size` bytes value at `offset` in the header
+    fn read_fixed(&self, offset: usize, size: usize) -> Option<u64> {
+        let range = offset..offset.checked_add(size)?;
+        match size {
+            0x02 => u16::endian_parse(range, self.data, &self.e_data)
+                .ok()
+                .map(|value| value as u64),
+            0x04 => u32::endian_parse(range, self.data, &self.e_data)
+                .ok()
+                .map(|value| value as u64),
+            _ => u64::endian_parse(range, self.data, &self.e_data).ok(),
+        }
+    }
+}
+
+/// Decode the LEB128 value at the start of `data` and returns it with its
+/// size in bytes
+fn read_leb128(data: &[u8], signed: bool) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    for (ndx, &byte) in data.iter().enumerate() {
+        if shift < 64 {
+            value |= ((byte & 0x7f) as u64) << shift;
+        }
+        shift += 7;
+        if byte & 0x80 == 0 {
+            // Sign extend from the last bit of the value
+            if signed && shift < 64 && byte & 0x40 != 0 {
+                value |= u64::MAX << shift;
+            }
+            return Some((value, ndx + 1));
+        }
+    }
+    None
+}
@@ -1,26 +1,56 @@
 use crate::Result;
-use crate::utils::Integer;
-use crate::Error;
+use crate::utils::{narrow, saturating_usize, Integer};
+use crate::{Error, ParseOptions};
+use crate::section::{SectionHeader, SHN_LORESERVE};
+use crate::program::PN_XNUM;
+
+/// Mask of the ARM EABI version in the `e_flags`
+pub const EF_ARM_EABIMASK: u32 = 0xff000000;
+/// ARM soft float calling convention
+pub const EF_ARM_ABI_FLOAT_SOFT: u32 = 0x00000200;
+/// ARM hard float calling convention
+pub const EF_ARM_ABI_FLOAT_HARD: u32 = 0x00000400;
+
+/// RISC-V compressed instructions are used
+pub const EF_RISCV_RVC: u32 = 0x0001;
+/// Mask of the RISC-V floating point calling convention
+pub const EF_RISCV_FLOAT_ABI: u32 = 0x0006;
+/// RISC-V embedded base integer ISA
+pub const EF_RISCV_RVE: u32 = 0x0008;
+
+/// MIPS position independent code
+pub const EF_MIPS_PIC: u32 = 0x00000002;
+/// MIPS code using position independent calls
+pub const EF_MIPS_CPIC: u32 = 0x00000004;
+/// Mask of the MIPS ABI in the `e_flags`
+pub const EF_MIPS_ABI: u32 = 0x0000f000;
+/// Mask of the MIPS architecture level in the `e_flags`
+pub const EF_MIPS_ARCH: u32 = 0xf0000000;
 
 /// Elf file header type to store the file header information
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileHeader {
+    /// Raw elf identification bytes at the start of the file
+    pub e_ident: [u8; 16],
     /// Elf bitness
     pub e_class: ElfClass,
     /// Elf data encodings
     pub e_data: ElfData,
     /// Elf OS ABI
     pub e_abi: ElfOsAbi,
+    /// Version of the OS ABI which is interpreted according to `e_abi`
+    pub e_abi_version: u8,
     /// Elf file type
     pub e_type: ElfType,
     /// Elf machine ISA
     pub e_machine: ElfMachine,
     /// Elf virtual address entry point
-    pub e_entry: usize,
+    pub e_entry: u64,
     /// Pointer to the start of the program header table
-    pub e_phoff: usize,
+    pub e_phoff: u64,
     /// Pointer to the start of the section header table
-    pub e_shoff: usize,
+    pub e_shoff: u64,
     /// Elf flags interpretation of this flag depends on the target 
     /// architecture
     pub e_flags: u32,
@@ -28,18 +58,19 @@ pub struct FileHeader {
     pub e_ehsize: u16,
     /// Elf program header table entry size
     pub e_phentsize: u16,
-    /// Elf program header table entry size
-    pub e_phnum: u16,
+    /// Elf program header table entry count
+    pub e_phnum: usize,
     /// Elf section header table entry size
     pub e_shentsize: u16,
     /// Elf Section header table entry count
-    pub e_shnum: u16,
+    pub e_shnum: usize,
     /// Elf section header string table index that contains section names
     pub e_shstrndx: u16,
 }
 
 /// ElfClass specifies elf architecture and bitness
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElfClass {
     None,
     Class32,
@@ -48,6 +79,7 @@ pub enum ElfClass {
 
 /// ElfData specifies elf data encodings
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElfData {
     None,
     ElfData2Lsb,
@@ -56,6 +88,7 @@ pub enum ElfData {
 
 /// ElfOSAbi specifies OS ABI of the elf file
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElfOsAbi {
     Sysv,
     Hpux,
@@ -75,28 +108,179 @@ pub enum ElfOsAbi {
 
 /// ElfType defines elf object type
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElfType {
     None,
     Relocatable,
     Executable,
     SharedObject,
     CoreFile,
-    OsSpecific,
-    CpuSpecific,
+    OsSpecific(u16),
+    CpuSpecific(u16),
+    Unknown(u16),
+}
+
+/// ArmFloatAbi specifies the floating point calling convention of ARM
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArmFloatAbi {
+    /// No calling convention is recorded
+    Unspecified,
+    /// Floating point values are passed in the integer registers
+    Soft,
+    /// Floating point values are passed in the floating point registers
+    Hard,
+}
+
+/// RiscvFloatAbi specifies the floating point calling convention of RISC-V
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RiscvFloatAbi {
+    /// Floating point values are passed in the integer registers
+    Soft,
+    /// Single precision values are passed in the floating point registers
+    Single,
+    /// Double precision values are passed in the floating point registers
+    Double,
+    /// Quad precision values are passed in the floating point registers
+    Quad,
 }
 
 /// ElfMachine Specifies machine ISA type
 /// As this might get too big we will not specify all the available machine
 /// types in the libc
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElfMachine {
     None,
+    Sparc,
     Intel80386,
+    Mips,
+    PowerPc,
+    PowerPc64,
+    S390,
+    Arm,
     Amd64,
+    Aarch64,
     Riscv,
-    Arm,
     Bpf,
-    UnDefined,
+    LoongArch,
+    UnDefined(u16),
+}
+
+impl core::fmt::Display for ElfClass {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ElfClass::None    => "none",
+            ElfClass::Class32 => "ELF32",
+            ElfClass::Class64 => "ELF64",
+        })
+    }
+}
+
+impl core::fmt::Display for ElfData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ElfData::None        => "none",
+            ElfData::ElfData2Lsb => "2's complement, little endian",
+            ElfData::ElfData2Msb => "2's complement, big endian",
+        })
+    }
+}
+
+impl core::fmt::Display for ElfOsAbi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ElfOsAbi::Sysv       => "UNIX - System V",
+            ElfOsAbi::Hpux       => "UNIX - HP-UX",
+            ElfOsAbi::Netbsd     => "UNIX - NetBSD",
+            ElfOsAbi::Gnu        => "UNIX - GNU",
+            ElfOsAbi::Solaris    => "UNIX - Solaris",
+            ElfOsAbi::Aix        => "UNIX - AIX",
+            ElfOsAbi::Irix       => "UNIX - IRIX",
+            ElfOsAbi::Freebsd    => "UNIX - FreeBSD",
+            ElfOsAbi::Tru64      => "UNIX - TRU64",
+            ElfOsAbi::Modesto    => "Novell - Modesto",
+            ElfOsAbi::Openbsd    => "UNIX - OpenBSD",
+            ElfOsAbi::Armeabi    => "ARM EABI",
+            ElfOsAbi::Arm        => "ARM",
+            ElfOsAbi::Standalone => "Standalone App",
+        })
+    }
+}
+
+impl core::fmt::Display for ElfType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ElfType::None         => "NONE",
+            ElfType::Relocatable  => "REL",
+            ElfType::Executable   => "EXEC",
+            ElfType::SharedObject => "DYN",
+            ElfType::CoreFile     => "CORE",
+            ElfType::OsSpecific(_)  => "OS Specific",
+            ElfType::CpuSpecific(_) => "Processor Specific",
+            ElfType::Unknown(_)     => "Unknown",
+        })
+    }
+}
+
+impl core::fmt::Display for ElfMachine {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ElfMachine::None       => "None",
+            ElfMachine::Sparc      => "Sparc",
+            ElfMachine::Intel80386 => "Intel 80386",
+            ElfMachine::Mips       => "MIPS",
+            ElfMachine::PowerPc    => "PowerPC",
+            ElfMachine::PowerPc64  => "PowerPC64",
+            ElfMachine::S390       => "IBM S/390",
+            ElfMachine::Arm        => "ARM",
+            ElfMachine::Amd64      => "x86-64",
+            ElfMachine::Aarch64    => "AArch64",
+            ElfMachine::Riscv      => "RISC-V",
+            ElfMachine::Bpf        => "Linux BPF",
+            ElfMachine::LoongArch  => "LoongArch",
+            ElfMachine::UnDefined(_) => "Unknown",
+        })
+    }
+}
+
+impl ElfType {
+    /// Returns the raw `e_type` value of the elf type
+    /// Range and unknown variants return the value they hold
+    pub fn raw_value(self) -> u16 {
+        match self {
+            ElfType::None         => 0x0000,
+            ElfType::Relocatable  => 0x0001,
+            ElfType::Executable   => 0x0002,
+            ElfType::SharedObject => 0x0003,
+            ElfType::CoreFile     => 0x0004,
+            ElfType::OsSpecific(value)  => value,
+            ElfType::CpuSpecific(value) => value,
+            ElfType::Unknown(value)     => value,
+        }
+    }
+}
+
+impl ElfMachine {
+    /// Returns the raw `e_machine` value of the machine
+    /// `UnDefined` returns the value it was parsed from
+    pub fn raw_value(self) -> u16 {
+        match self {
+            ElfMachine::None       => 0x0000,
+            ElfMachine::Sparc      => 0x0002,
+            ElfMachine::Intel80386 => 0x0003,
+            ElfMachine::Mips       => 0x0008,
+            ElfMachine::PowerPc    => 0x0014,
+            ElfMachine::PowerPc64  => 0x0015,
+            ElfMachine::S390       => 0x0016,
+            ElfMachine::Arm        => 0x0028,
+            ElfMachine::Amd64      => 0x003e,
+            ElfMachine::Aarch64    => 0x00b7,
+            ElfMachine::Riscv      => 0x00f3,
+            ElfMachine::Bpf        => 0x00f7,
+            ElfMachine::LoongArch  => 0x0102,
+            ElfMachine::UnDefined(value) => value,
+        }
+    }
 }
 
 impl Default for FileHeader {
@@ -109,9 +293,11 @@ impl FileHeader {
     /// The default `FileHeader` constructor
     pub fn new() -> Self {
         FileHeader {
+            e_ident:       [0; 16],
             e_class:       ElfClass::None,
             e_data:        ElfData::None,
             e_abi:         ElfOsAbi::Standalone,
+            e_abi_version: 0,
             e_type:        ElfType::None,
             e_machine:     ElfMachine::None,
             e_entry:       0,
@@ -127,12 +313,27 @@ impl FileHeader {
         }
     }
     /// Parse the elf header and populate the fields
-    pub fn parse(mut self, elf: &[u8]) -> Result<Self> {
+    pub fn parse(self, elf: &[u8]) -> Result<Self> {
+        self.parse_with_options(elf, &ParseOptions::default())
+    }
+
+    /// Parse the elf header with the strictness given by `opts`
+    pub fn parse_with_options(
+        mut self,
+        elf: &[u8],
+        opts: &ParseOptions,
+    ) -> Result<Self> {
         // Get the elf magic number from the start of the file
-        if elf.get(0x00..0x04) != Some(b"\x7FELF") {
+        if !opts.ignore_bad_magic && elf.get(0x00..0x04) != Some(b"\x7FELF") {
             return Err(Error::BadElf);
         }
 
+        // Keep the raw identification bytes
+        self.e_ident = elf
+            .get(0x00..0x10)
+            .and_then(|ident| ident.try_into().ok())
+            .ok_or(Error::TruncatedElf)?;
+
         // Check for the bitness of the elf file and report it back as ElfClass
         // Option type is cloned to avoid storing numbers in the .data section
         self.e_class = if elf.get(0x04) == Some(&1) {
@@ -143,6 +344,10 @@ impl FileHeader {
             ElfClass::None
         };
 
+        if opts.require_valid_class && self.e_class == ElfClass::None {
+            return Err(Error::UnsupportedClass);
+        }
+
         #[cfg(target_pointer_width = "32")]
         if self.e_class == ElfClass::Class64 || 
             self.e_class == ElfClass::None {
@@ -159,7 +364,7 @@ impl FileHeader {
         };
 
         // Check for the elf version currently this value must be 1
-        if elf.get(0x06) != Some(&1) {
+        if !opts.ignore_bad_version && elf.get(0x06) != Some(&1) {
             return Err(Error::BadElf);
         }
 
@@ -186,37 +391,48 @@ impl FileHeader {
             }
         };
 
-        // Get the abi version but discard it for future
-        let _e_abi_ver = elf.get(0x08).unwrap_or(&0);
+        // Get the abi version
+        self.e_abi_version = self.e_ident[0x08];
 
-        // Discard the padding byte at 0x09 to 0x10
-        let _padding = elf.get(0x09..0x10).unwrap_or(&[0u8; 7]);
+        // The padding bytes at 0x09 to 0x10 are only kept in `e_ident`
 
         // Identify the elf type
-        self.e_type = match elf.get(0x10..0x12) {
-            Some(&[0x00, 0x00]) => ElfType::None,
-            Some(&[0x01, 0x00]) => ElfType::Relocatable,
-            Some(&[0x02, 0x00]) => ElfType::Executable,
-            Some(&[0x03, 0x00]) => ElfType::SharedObject,
-            Some(&[0x04, 0x00]) => ElfType::CoreFile,
-            Some(&[0xfe, _])    => ElfType::OsSpecific,
-            Some(&[0xff, _])    => ElfType::CpuSpecific,
-            _ => ElfType::None,
+        let e_type = u16::endian_parse(0x10..0x12, elf, &self.e_data)?;
+        self.e_type = match e_type {
+            0x0000          => ElfType::None,
+            0x0001          => ElfType::Relocatable,
+            0x0002          => ElfType::Executable,
+            0x0003          => ElfType::SharedObject,
+            0x0004          => ElfType::CoreFile,
+            0xfe00..=0xfeff => ElfType::OsSpecific(e_type),
+            0xff00..=0xffff => ElfType::CpuSpecific(e_type),
+            _ => ElfType::Unknown(e_type),
         };
 
         // Get the target ISA
-        self.e_machine = match elf.get(0x12..0x14) {
-            Some(&[0x03, 0x00]) => ElfMachine::Intel80386,
-            Some(&[0x3e, 0x00]) => ElfMachine::Amd64,
-            Some(&[0x28, 0x00]) => ElfMachine::Arm,
-            Some(&[0xf3, 0x00]) => ElfMachine::Riscv,
-            Some(&[0xf7, 0x00]) => ElfMachine::Bpf,
-            _ => ElfMachine::UnDefined,
+        let machine = u16::endian_parse(0x12..0x14, elf, &self.e_data)?;
+        self.e_machine = match machine {
+            0x0000 => ElfMachine::None,
+            0x0002 => ElfMachine::Sparc,
+            0x0003 => ElfMachine::Intel80386,
+            0x0008 => ElfMachine::Mips,
+            0x0014 => ElfMachine::PowerPc,
+            0x0015 => ElfMachine::PowerPc64,
+            0x0016 => ElfMachine::S390,
+            0x0028 => ElfMachine::Arm,
+            0x003e => ElfMachine::Amd64,
+            0x00b7 => ElfMachine::Aarch64,
+            0x00f3 => ElfMachine::Riscv,
+            0x00f7 => ElfMachine::Bpf,
+            0x0102 => ElfMachine::LoongArch,
+            _ => ElfMachine::UnDefined(machine),
         };
 
         // Check for the elf version for another time apparently this has more
         // options like none and current version which is 1
-        if elf.get(0x14..0x18) != Some(&[0x01, 0x00, 0x00, 0x00]) {
+        if !opts.ignore_bad_version
+            && u32::endian_parse(0x14..0x18, elf, &self.e_data).ok() != Some(1)
+        {
             return Err(Error::BadElf);
         }
 
@@ -234,7 +450,7 @@ impl FileHeader {
         if self.e_class == ElfClass::Class64 || 
             self.e_class == ElfClass::None {
             // Get the elf virtual address entry point
-            self.e_entry = usize::endian_parse(pos..next,
+            self.e_entry = u64::endian_parse(pos..next,
                 elf, &self.e_data)?;
 
             // Move the position to the new header part
@@ -244,7 +460,7 @@ impl FileHeader {
             next = pos + 0x08;
             
             // Get the elf program header offset
-            self.e_phoff = usize::endian_parse(pos..next,
+            self.e_phoff = u64::endian_parse(pos..next,
                 elf, &self.e_data)?;
 
             // Move the position to the new header part
@@ -254,13 +470,13 @@ impl FileHeader {
             next = pos + 0x08;
 
             // Get the elf section header offset
-            self.e_shoff = usize::endian_parse(pos..next,
+            self.e_shoff = u64::endian_parse(pos..next,
                 elf, &self.e_data)?;
 
         } else if self.e_class == ElfClass::Class32 {
             // Get the elf virtual address entry point
             self.e_entry = u32::endian_parse(pos..next,
-                elf, &self.e_data)? as usize;
+                elf, &self.e_data)? as u64;
 
 
             // Move the position to the new header part
@@ -271,7 +487,7 @@ impl FileHeader {
 
             // Get the elf program header offset
             self.e_phoff = u32::endian_parse(pos..next,
-                elf, &self.e_data)? as usize;
+                elf, &self.e_data)? as u64;
 
             // Move the position to the new header part
             pos  = next;
@@ -281,7 +497,7 @@ impl FileHeader {
 
             // Get the elf section header offset
             self.e_shoff = u32::endian_parse(pos..next,
-                elf, &self.e_data)? as usize;
+                elf, &self.e_data)? as u64;
         }
 
         // Move the position to the new header part
@@ -306,9 +522,9 @@ impl FileHeader {
         // Move the position to the new header part
         pos += 0x02;
 
-        // Get the elf program header entry size
+        // Get the elf program header entry count
         self.e_phnum = u16::endian_parse(pos..(pos + 0x02),
-            elf, &self.e_data)?;
+            elf, &self.e_data)? as usize;
 
         // Move the position to the new header part
         pos += 0x02;
@@ -322,7 +538,7 @@ impl FileHeader {
 
         // Get the elf section header table entry count
         self.e_shnum = u16::endian_parse(pos..(pos + 0x02),
-            elf, &self.e_data)?;
+            elf, &self.e_data)? as usize;
 
         // Move the position to the new header part
         let pos: usize = pos + 0x02;
@@ -331,6 +547,200 @@ impl FileHeader {
         self.e_shstrndx = u16::endian_parse(pos..(pos + 0x02),
             elf, &self.e_data)?;
 
+        // A zero section count with a section header table means the real
+        // count is stored in the `sh_size` of the first section header
+        if self.e_shnum == 0 && self.e_shoff != 0 && self.e_shentsize != 0 {
+            self.e_shnum = usize::try_from(self.first_section(elf)?.sh_size)
+                .map_err(|_err| Error::Overflow)?;
+
+            // The count is not limited by the header field so make sure
+            // the whole table is inside the file before trusting it
+            self.e_shnum
+                .checked_mul(self.e_shentsize as usize)
+                .and_then(|size| self.e_shoff.checked_add(size as u64))
+                .filter(|&end| end <= elf.len() as u64)
+                .ok_or(Error::TruncatedElf)?;
+        }
+
+        // A program header count of `PN_XNUM` means the real count is stored
+        // in the `sh_info` of the first section header
+        if self.e_phnum == PN_XNUM as usize {
+            self.e_phnum = self.first_section(elf)?.sh_info as usize;
+        }
+
+        // Refuse section header tables bigger than the caller allows
+        if opts.max_section_count.is_some_and(|max| self.e_shnum > max) {
+            return Err(Error::BadElf);
+        }
+
         Ok(self)
     }
+
+    /// Write the file header into `buf` with the layout and the byte order
+    /// of the elf class and data encoding
+    /// This is the inverse of `parse` where the OS ABI and the padding are
+    /// taken from `e_ident`
+    /// Counts which do not fit in the header are written as escapes so the
+    /// real counts must be stored in the first section header
+    pub fn emit(
+        &self,
+        buf: &mut [u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<()> {
+        // Write the identification bytes with the requested layout
+        let mut ident = self.e_ident;
+        ident[0x04] = match class {
+            ElfClass::Class32 => 1,
+            ElfClass::Class64 => 2,
+            ElfClass::None    => return Err(Error::UnsupportedClass),
+        };
+        ident[0x05] = match data {
+            ElfData::ElfData2Lsb => 1,
+            ElfData::ElfData2Msb => 2,
+            ElfData::None        => 0,
+        };
+        ident[0x08] = self.e_abi_version;
+        buf.get_mut(0x00..0x10)
+            .ok_or(Error::OffsetCalculationFailure)?
+            .copy_from_slice(&ident);
+
+        self.e_type.raw_value().endian_emit(0x10..0x12, buf, &data)?;
+        self.e_machine.raw_value().endian_emit(0x12..0x14, buf, &data)?;
+        1u32.endian_emit(0x14..0x18, buf, &data)?;
+
+        // Branch and emit according to the elf architecture class
+        let pos = if class == ElfClass::Class32 {
+            narrow(self.e_entry)?.endian_emit(0x18..0x1c, buf, &data)?;
+            narrow(self.e_phoff)?.endian_emit(0x1c..0x20, buf, &data)?;
+            narrow(self.e_shoff)?.endian_emit(0x20..0x24, buf, &data)?;
+            0x24
+        } else {
+            self.e_entry.endian_emit(0x18..0x20, buf, &data)?;
+            self.e_phoff.endian_emit(0x20..0x28, buf, &data)?;
+            self.e_shoff.endian_emit(0x28..0x30, buf, &data)?;
+            0x30
+        };
+
+        // Counts from the first section header are escaped in the header
+        let phnum = u16::try_from(self.e_phnum)
+            .ok()
+            .filter(|&phnum| phnum < PN_XNUM)
+            .unwrap_or(PN_XNUM);
+        let shnum = u16::try_from(self.e_shnum)
+            .ok()
+            .filter(|&shnum| shnum < SHN_LORESERVE)
+            .unwrap_or(0);
+
+        self.e_flags.endian_emit(pos..pos + 0x04, buf, &data)?;
+        self.e_ehsize.endian_emit(pos + 0x04..pos + 0x06, buf, &data)?;
+        self.e_phentsize.endian_emit(pos + 0x06..pos + 0x08, buf, &data)?;
+        phnum.endian_emit(pos + 0x08..pos + 0x0a, buf, &data)?;
+        self.e_shentsize.endian_emit(pos + 0x0a..pos + 0x0c, buf, &data)?;
+        shnum.endian_emit(pos + 0x0c..pos + 0x0e, buf, &data)?;
+        self.e_shstrndx.endian_emit(pos + 0x0e..pos + 0x10, buf, &data)?;
+
+        Ok(())
+    }
+
+    /// Returns the ARM EABI version stored in the top byte of `e_flags`
+    /// Zero means the file predates the EABI
+    #[must_use]
+    pub fn arm_eabi_version(&self) -> Option<u8> {
+        if self.e_machine != ElfMachine::Arm {
+            return None;
+        }
+        Some(((self.e_flags & EF_ARM_EABIMASK) >> 24) as u8)
+    }
+
+    /// Returns true if the ARM entry point is Thumb code
+    /// The interworking convention marks Thumb addresses with the lowest bit
+    #[must_use]
+    pub fn arm_is_thumb_entry(&self) -> Option<bool> {
+        if self.e_machine != ElfMachine::Arm {
+            return None;
+        }
+        Some(self.e_entry & 1 != 0)
+    }
+
+    /// Returns the ARM floating point calling convention
+    #[must_use]
+    pub fn arm_float_abi(&self) -> Option<ArmFloatAbi> {
+        if self.e_machine != ElfMachine::Arm {
+            return None;
+        }
+        Some(if self.e_flags & EF_ARM_ABI_FLOAT_HARD != 0 {
+            ArmFloatAbi::Hard
+        } else if self.e_flags & EF_ARM_ABI_FLOAT_SOFT != 0 {
+            ArmFloatAbi::Soft
+        } else {
+            ArmFloatAbi::Unspecified
+        })
+    }
+
+    /// Returns the RISC-V floating point calling convention
+    #[must_use]
+    pub fn riscv_float_abi(&self) -> Option<RiscvFloatAbi> {
+        if self.e_machine != ElfMachine::Riscv {
+            return None;
+        }
+        Some(match self.e_flags & EF_RISCV_FLOAT_ABI {
+            0x0000 => RiscvFloatAbi::Soft,
+            0x0002 => RiscvFloatAbi::Single,
+            0x0004 => RiscvFloatAbi::Double,
+            _      => RiscvFloatAbi::Quad,
+        })
+    }
+
+    /// Returns true if the RISC-V code uses compressed instructions
+    #[must_use]
+    pub fn riscv_is_rvc(&self) -> Option<bool> {
+        if self.e_machine != ElfMachine::Riscv {
+            return None;
+        }
+        Some(self.e_flags & EF_RISCV_RVC != 0)
+    }
+
+    /// Returns the MIPS ABI bits of `e_flags` like `0x1000` for O32
+    /// Files of the N32 and N64 ABIs store zero here
+    #[must_use]
+    pub fn mips_abi(&self) -> Option<u32> {
+        if self.e_machine != ElfMachine::Mips {
+            return None;
+        }
+        Some(self.e_flags & EF_MIPS_ABI)
+    }
+
+    /// Returns the MIPS architecture level bits of `e_flags`
+    #[must_use]
+    pub fn mips_arch(&self) -> Option<u32> {
+        if self.e_machine != ElfMachine::Mips {
+            return None;
+        }
+        Some(self.e_flags & EF_MIPS_ARCH)
+    }
+
+    /// Returns true if the MIPS code is position independent
+    #[must_use]
+    pub fn mips_is_pic(&self) -> Option<bool> {
+        if self.e_machine != ElfMachine::Mips {
+            return None;
+        }
+        Some(self.e_flags & (EF_MIPS_PIC | EF_MIPS_CPIC) != 0)
+    }
+
+    /// Parse the first section header which holds the extended values of the
+    /// file header fields
+    fn first_section(&self, elf: &[u8]) -> Result<SectionHeader> {
+        let start = saturating_usize(self.e_shoff);
+        let end = start
+            .checked_add(self.e_shentsize as usize)
+            .ok_or(Error::Overflow)?;
+
+        SectionHeader::new().parse(
+            elf.get(start..end).ok_or(Error::TruncatedElf)?,
+            self.e_class,
+            self.e_data,
+        )
+    }
 }
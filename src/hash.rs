@@ -0,0 +1,373 @@
+use crate::Result;
+use crate::Error;
+use crate::Elf;
+use crate::utils::Integer;
+use crate::file::{ElfData, ElfClass};
+use crate::section::{SectionHeader, SymTabEnt, SymTabIterator};
+
+/// GNU style hash table stored in the `ShtGnuHash` section
+/// It is made of a header, a bloom filter, the buckets and the hash chains
+#[derive(Debug, Clone, Copy)]
+pub struct GnuHashTable<'a> {
+    /// Number of hash buckets
+    pub nbuckets: u32,
+    /// Index of the first symbol in the dynamic symbol table that is
+    /// accessible through the hash table
+    pub symoffset: u32,
+    /// Number of bloom filter words
+    pub bloom_size: u32,
+    /// Shift count used by the bloom filter
+    pub bloom_shift: u32,
+    /// Section data including the header
+    data: &'a [u8],
+    /// Elf class used for the bloom filter word size
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    e_data: ElfData,
+}
+
+/// SysV style hash table stored in the `ShtHash` section
+/// It is made of a header, the buckets and the hash chains
+#[derive(Debug, Clone, Copy)]
+pub struct SysVHashTable<'a> {
+    /// Number of hash buckets
+    pub nbucket: u32,
+    /// Number of hash chain entries which equals the number of symbols
+    pub nchain: u32,
+    /// Section data including the header
+    data: &'a [u8],
+    /// Elf endianness used for parsing
+    e_data: ElfData,
+}
+
+/// Lookup of the symbols of the dynamic symbol table by name
+/// It lets the caller use the fastest lookup available in the file without
+/// knowing which one it is
+pub trait SymbolLookup {
+    /// Look up the symbol `name` in the dynamic symbol table and returns
+    /// it with its index which is also its index in `ShtGnuVerSym`
+    fn lookup_index(&self, name: &str) -> Option<(usize, SymTabEnt)>;
+
+    /// Look up the symbol `name` in the dynamic symbol table
+    fn lookup(&self, name: &str) -> Option<SymTabEnt> {
+        self.lookup_index(name).map(|(_, sym)| sym)
+    }
+}
+
+/// `SymbolLookup` backed by the GNU hash table
+#[derive(Debug, Clone, Copy)]
+pub struct GnuHashLookup<'a> {
+    /// Hash table of the dynamic symbol table
+    table: GnuHashTable<'a>,
+    /// Iterator over the dynamic symbol table
+    symtab: SymTabIterator<'a>,
+    /// Dynamic string table holding the symbol names
+    strtab: SectionHeader,
+    /// A reference to the elf file
+    elf: &'a Elf<'a>,
+}
+
+/// `SymbolLookup` backed by the SysV hash table
+#[derive(Debug, Clone, Copy)]
+pub struct SysVHashLookup<'a> {
+    /// Hash table of the dynamic symbol table
+    table: SysVHashTable<'a>,
+    /// Iterator over the dynamic symbol table
+    symtab: SymTabIterator<'a>,
+    /// Dynamic string table holding the symbol names
+    strtab: SectionHeader,
+    /// A reference to the elf file
+    elf: &'a Elf<'a>,
+}
+
+/// `SymbolLookup` scanning the whole dynamic symbol table when the file
+/// has no hash table
+#[derive(Debug, Clone, Copy)]
+pub struct LinearLookup<'a> {
+    /// Iterator over the dynamic symbol table
+    symtab: SymTabIterator<'a>,
+    /// Dynamic string table holding the symbol names
+    strtab: SectionHeader,
+    /// A reference to the elf file
+    elf: &'a Elf<'a>,
+}
+
+impl<'a> GnuHashTable<'a> {
+    /// Parse the hash table header from the `ShtGnuHash` section data
+    pub fn parse(
+        data: &'a [u8],
+        class: ElfClass,
+        e_data: ElfData,
+    ) -> Result<Self> {
+        let table = GnuHashTable {
+            nbuckets: u32::endian_parse(0x00..0x04, data, &e_data)?,
+            symoffset: u32::endian_parse(0x04..0x08, data, &e_data)?,
+            bloom_size: u32::endian_parse(0x08..0x0c, data, &e_data)?,
+            bloom_shift: u32::endian_parse(0x0c..0x10, data, &e_data)?,
+            data,
+            class,
+            e_data,
+        };
+
+        // Empty buckets or bloom filter would make every lookup fail
+        if table.nbuckets == 0 || table.bloom_size == 0 {
+            return Err(Error::BadElf);
+        }
+
+        // The shift is applied to the 32-bit hash so a larger one is bogus
+        if table.bloom_shift >= 32 {
+            return Err(Error::BadElf);
+        }
+
+        Ok(table)
+    }
+
+    /// Look up the symbol `name` in the dynamic symbol table `symtab` using
+    /// the hash table and the dynamic string table `strtab`
+    #[must_use]
+    pub fn lookup(
+        &self,
+        name: &str,
+        symtab: SymTabIterator<'a>,
+        strtab: &SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Option<SymTabEnt> {
+        self.lookup_index(name, symtab, strtab, elf).map(|(_, sym)| sym)
+    }
+
+    /// Look up the symbol `name` like `lookup()` and returns it with its
+    /// index in the dynamic symbol table
+    #[must_use]
+    pub fn lookup_index(
+        &self,
+        name: &str,
+        symtab: SymTabIterator<'a>,
+        strtab: &SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Option<(usize, SymTabEnt)> {
+        let hash = gnu_hash(name.as_bytes());
+
+        // Check the bloom filter to reject most of the missing symbols
+        let bits: u32 = if self.class == ElfClass::Class32 { 32 } else { 64 };
+        let word = self.bloom((hash / bits) % self.bloom_size)?;
+        let mask = (1u64 << (hash % bits))
+            | (1u64 << ((hash >> self.bloom_shift) % bits));
+        if word & mask != mask {
+            return None;
+        }
+
+        // Walk the chain of the bucket until the last entry
+        let mut ndx = self.bucket(hash % self.nbuckets)?;
+        if ndx < self.symoffset {
+            return None;
+        }
+        loop {
+            let chain = self.chain(ndx - self.symoffset)?;
+            // The lowest bit of the chain value marks the end of the chain
+            if (hash | 1) == (chain | 1) {
+                let mut symbols = symtab;
+                let sym = symbols.nth(ndx as usize)?;
+                if elf.sym_name(sym, strtab) == Some(name) {
+                    return Some((ndx as usize, sym));
+                }
+            }
+            if chain & 1 != 0 {
+                return None;
+            }
+            ndx = ndx.checked_add(1)?;
+        }
+    }
+
+    /// Returns the bloom filter word at `ndx`
+    fn bloom(&self, ndx: u32) -> Option<u64> {
+        let start = self.offset(0, ndx as u64 * self.word_size())?;
+        let end = start.checked_add(self.word_size() as usize)?;
+        if self.class == ElfClass::Class32 {
+            u32::endian_parse(start..end, self.data, &self.e_data)
+                .ok()
+                .map(|word| word as u64)
+        } else {
+            u64::endian_parse(start..end, self.data, &self.e_data).ok()
+        }
+    }
+
+    /// Returns the first symbol index of the bucket at `ndx`
+    fn bucket(&self, ndx: u32) -> Option<u32> {
+        let bloom = self.bloom_size as u64 * self.word_size();
+        let start = self.offset(bloom, ndx as u64 * 0x04)?;
+        u32::endian_parse(start..start + 0x04, self.data, &self.e_data).ok()
+    }
+
+    /// Returns the hash chain value at `ndx`
+    fn chain(&self, ndx: u32) -> Option<u32> {
+        let bloom = self.bloom_size as u64 * self.word_size();
+        let buckets = self.nbuckets as u64 * 0x04;
+        let start = self.offset(bloom + buckets, ndx as u64 * 0x04)?;
+        u32::endian_parse(start..start + 0x04, self.data, &self.e_data).ok()
+    }
+
+    /// Calculate the offset of an entry in the section data from the offset
+    /// of its array after the header
+    fn offset(&self, array: u64, entry: u64) -> Option<usize> {
+        usize::try_from(0x10 + array + entry).ok()
+    }
+
+    /// Returns the size of a bloom filter word in bytes
+    fn word_size(&self) -> u64 {
+        if self.class == ElfClass::Class32 {
+            0x04
+        } else {
+            0x08
+        }
+    }
+}
+
+impl<'a> SysVHashTable<'a> {
+    /// Parse the hash table header from the `ShtHash` section data
+    pub fn parse(data: &'a [u8], e_data: ElfData) -> Result<Self> {
+        let table = SysVHashTable {
+            nbucket: u32::endian_parse(0x00..0x04, data, &e_data)?,
+            nchain: u32::endian_parse(0x04..0x08, data, &e_data)?,
+            data,
+            e_data,
+        };
+
+        // Empty buckets would make every lookup fail
+        if table.nbucket == 0 {
+            return Err(Error::BadElf);
+        }
+
+        Ok(table)
+    }
+
+    /// Look up the symbol `name` in the dynamic symbol table `symtab_iter`
+    /// using the hash table and the dynamic string table `strtab`
+    #[must_use]
+    pub fn lookup(
+        &self,
+        name: &str,
+        symtab_iter: SymTabIterator<'a>,
+        strtab: &SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Option<SymTabEnt> {
+        self.lookup_index(name, symtab_iter, strtab, elf)
+            .map(|(_, sym)| sym)
+    }
+
+    /// Look up the symbol `name` like `lookup()` and returns it with its
+    /// index in the dynamic symbol table
+    #[must_use]
+    pub fn lookup_index(
+        &self,
+        name: &str,
+        symtab_iter: SymTabIterator<'a>,
+        strtab: &SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Option<(usize, SymTabEnt)> {
+        let hash = elf_hash(name.as_bytes());
+
+        // Walk the chain of the bucket until the undefined symbol index
+        // The chain is bounded by `nchain` so a looping chain is not fatal
+        let mut ndx = self.entry((hash % self.nbucket) as u64)?;
+        for _ in 0..self.nchain {
+            if ndx == 0 {
+                break;
+            }
+            let mut symbols = symtab_iter;
+            let sym = symbols.nth(ndx as usize)?;
+            if elf.sym_name(sym, strtab) == Some(name) {
+                return Some((ndx as usize, sym));
+            }
+            if ndx >= self.nchain {
+                break;
+            }
+            ndx = self.entry(self.nbucket as u64 + ndx as u64)?;
+        }
+
+        None
+    }
+
+    /// Returns the entry at `ndx` of the buckets and chains which directly
+    /// follow each other after the header
+    fn entry(&self, ndx: u64) -> Option<u32> {
+        let start = usize::try_from(0x08 + ndx * 0x04).ok()?;
+        u32::endian_parse(start..start + 0x04, self.data, &self.e_data).ok()
+    }
+}
+
+impl<'a> GnuHashLookup<'a> {
+    pub fn new(
+        table: GnuHashTable<'a>,
+        symtab: SymTabIterator<'a>,
+        strtab: SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Self {
+        GnuHashLookup { table, symtab, strtab, elf }
+    }
+}
+
+impl<'a> SymbolLookup for GnuHashLookup<'a> {
+    fn lookup_index(&self, name: &str) -> Option<(usize, SymTabEnt)> {
+        // The symbols before `symoffset` are not in the GNU hash table
+        self.table
+            .lookup_index(name, self.symtab, &self.strtab, self.elf)
+            .or_else(|| {
+                let symoffset = self.table.symoffset as usize;
+                self.symtab.take(symoffset).enumerate().find(|&(_, sym)| {
+                    self.elf.sym_name(sym, &self.strtab) == Some(name)
+                })
+            })
+    }
+}
+
+impl<'a> SysVHashLookup<'a> {
+    pub fn new(
+        table: SysVHashTable<'a>,
+        symtab: SymTabIterator<'a>,
+        strtab: SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Self {
+        SysVHashLookup { table, symtab, strtab, elf }
+    }
+}
+
+impl<'a> SymbolLookup for SysVHashLookup<'a> {
+    fn lookup_index(&self, name: &str) -> Option<(usize, SymTabEnt)> {
+        self.table.lookup_index(name, self.symtab, &self.strtab, self.elf)
+    }
+}
+
+impl<'a> LinearLookup<'a> {
+    pub fn new(
+        symtab: SymTabIterator<'a>,
+        strtab: SectionHeader,
+        elf: &'a Elf<'a>,
+    ) -> Self {
+        LinearLookup { symtab, strtab, elf }
+    }
+}
+
+impl<'a> SymbolLookup for LinearLookup<'a> {
+    fn lookup_index(&self, name: &str) -> Option<(usize, SymTabEnt)> {
+        let mut symbols = self.symtab.enumerate();
+        symbols.find(|&(_, sym)| {
+            self.elf.sym_name(sym, &self.strtab) == Some(name)
+        })
+    }
+}
+
+/// The SysV hash function of the symbol name
+pub fn elf_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |hash, &byte| {
+        let hash = (hash << 4).wrapping_add(byte as u32);
+        let high = hash & 0xf0000000;
+        (hash ^ (high >> 24)) & !high
+    })
+}
+
+/// The GNU hash function which is the djb2 hash of the symbol name
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |hash, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte as u32)
+    })
+}
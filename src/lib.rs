@@ -1,16 +1,45 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // TODO:Add methods to instantiate section types easily
 
 mod utils;
 
+pub mod dynamic;
+pub mod eh_frame;
 pub mod file;
+pub mod hash;
+pub mod note;
 pub mod program;
+pub mod relocation;
 pub mod section;
+pub mod version;
+
+use dynamic::{DynIterator, DynTag, DF_1_NOW, DF_1_PIE, DF_BIND_NOW};
+use eh_frame::EhFrameHdr;
+use file::{ElfClass, ElfData, ElfMachine, ElfType, FileHeader};
+use hash::{GnuHashLookup, LinearLookup, SymbolLookup, SysVHashLookup};
+use hash::{GnuHashTable, SysVHashTable};
+use note::{CoreNote, GnuPropertyIterator, NoteIterator, ELF_NOTE_GNU};
+use note::GnuBuildAttrIterator;
+use note::{NT_GNU_BUILD_ID, NT_GNU_PROPERTY_TYPE_0};
+use program::{ProgramHeader, ProgramIterator, ProgramType, TlsTemplate};
+use relocation::{RelEnt, RelIterator, RelaEnt, RelaIterator, RelrIterator};
+use relocation::{aarch64, arm, riscv, x86_64};
+use section::{CompressedSectionHeader, SectionHeader, SectionIterator};
+use section::{GnuDebugLink, ModinfoIterator, StrTabIterator};
+use section::{SectionFlags, SectionType};
+use section::{SHN_UNDEF, SHN_XINDEX};
+use utils::{bytes_at, contains, contains_range, str_at, Integer};
+use version::{VersionDefIterator, VersionNeedIterator, VersionSymIterator};
+use version::{VER_NDX_GLOBAL, VER_NDX_LOCAL};
 
-use file::FileHeader;
-use program::ProgramIterator;
-use section::{SectionHeader, SectionIterator, SectionType};
+pub use section::{SymBinding, SymTabEnt, SymTabIterator, SymType};
+pub use section::{ResolvedSymbol, SectionIndex, SymVisibility};
+#[cfg(feature = "alloc")]
+pub use section::SectionNameIndex;
 
 /// Elf type to store the parsed information
 /// Struct members are defined according to the elf.h C header
@@ -21,20 +50,72 @@ pub struct Elf<'a> {
     pub elf: &'a [u8],
     /// 'SectionType::ShtStrTab' reference so we only find it once
     pub shtstrtab: Option<SectionHeader>,
+    /// Strictness of the file header parsing
+    pub options: ParseOptions,
+}
+
+/// Options to relax or tighten the file header checks of `Elf::parse`
+/// The default options keep the strict checks
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Accept any elf version in the identification and the file header
+    pub ignore_bad_version: bool,
+    /// Accept files which do not start with the `\x7FELF` magic number
+    pub ignore_bad_magic: bool,
+    /// Reject files whose class is neither 32-bit nor 64-bit
+    pub require_valid_class: bool,
+    /// Reject files with more sections than this count with `BadElf`
+    pub max_section_count: Option<usize>,
 }
 
 /// Error enum to distinctify the error types
 #[derive(Debug)]
 pub enum Error {
+    /// Bad magic number or version
     BadElf,
     OffsetCalculationFailure,
     UnsupportedClass,
     UnreadableSection,
+    /// A required range is beyond the end of the file
+    TruncatedElf,
+    /// Checked arithmetic failed while calculating an offset
+    Overflow,
+    /// An alignment is not zero or a power of two
+    InvalidAlignment,
+    /// A section index is not below the number of sections
+    InvalidSectionIndex,
+    /// A referenced section does not exist
+    SectionNotFound,
+    /// A program header index is not below the number of program headers
+    InvalidProgramHeaderIndex,
 }
 
 /// Wrapper type for the error result
 type Result<T> = core::result::Result<T, Error>;
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Error::BadElf => "not a valid ELF file",
+            Error::OffsetCalculationFailure => "offset out of bounds",
+            Error::UnsupportedClass => {
+                "unsupported ELF class (must be 32- or 64-bit)"
+            }
+            Error::UnreadableSection => "section data is outside the file",
+            Error::TruncatedElf => "ELF file is truncated",
+            Error::Overflow => "offset calculation overflowed",
+            Error::InvalidAlignment => "alignment is not a power of two",
+            Error::InvalidSectionIndex => "section index out of range",
+            Error::SectionNotFound => "section not found",
+            Error::InvalidProgramHeaderIndex => {
+                "program header index out of range"
+            }
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
 impl<'a> core::fmt::Debug for Elf<'a> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // Display only the FileHeader not the elf file slice
@@ -47,15 +128,22 @@ impl<'a> core::fmt::Debug for Elf<'a> {
 impl<'a> Elf<'a> {
     /// The default `Elf` constructor
     pub fn new(elf: &'a [u8]) -> Self {
+        Self::new_with_options(elf, ParseOptions::default())
+    }
+
+    /// The `Elf` constructor with custom parsing options
+    pub fn new_with_options(elf: &'a [u8], opts: ParseOptions) -> Self {
         Elf {
             file_header: FileHeader::new(),
             elf,
             shtstrtab: None,
+            options: opts,
         }
     }
 
     /// Returns the `ProgramIterator` to use in a loop or an iterator
-    pub fn program_iter(&'a self) -> program::ProgramIterator {
+    #[must_use]
+    pub fn program_iter(&'a self) -> program::ProgramIterator<'a> {
         ProgramIterator::new(
             self.file_header.e_phoff,
             self.file_header.e_phentsize,
@@ -67,7 +155,8 @@ impl<'a> Elf<'a> {
     }
 
     /// Returns the `SectionIterator` to use in a loop or an iterator
-    pub fn section_iter(&'a self) -> section::SectionIterator {
+    #[must_use]
+    pub fn section_iter(&'a self) -> section::SectionIterator<'a> {
         SectionIterator::new(
             self.file_header.e_shoff,
             self.file_header.e_shentsize,
@@ -78,113 +167,4541 @@ impl<'a> Elf<'a> {
         )
     }
 
-    /// Returns the slice for the specified section
-    pub fn get_section(
-        &'a self,
-        sh: SectionHeader,
-    ) -> Result<&'a [u8]> {
-        Ok(self
-            .elf
-            .get(sh.sh_offset..(sh.sh_offset + sh.sh_size))
-            .ok_or(Error::UnreadableSection)?)
+    /// Returns the first section with the specified name
+    #[must_use]
+    pub fn find_section(&'a self, name: &str) -> Option<SectionHeader> {
+        self.section_iter()
+            .find(|&section| self.section_name(section) == Some(name))
     }
 
-    /// This function returns the section name from the shstrtab
-    pub fn section_name(&'a self, sh: SectionHeader) -> Option<&str> {
-        if let Some(shtstrtab) = self.shtstrtab {
-            // FIXME: this should use the `get_section` function
-            if let Some(strtab) = self.elf.get(
-                shtstrtab.sh_offset..(shtstrtab.sh_offset + shtstrtab.sh_size),
-            ) {
-                let mut ndx: usize = 0;
-                let it = strtab.iter().skip(sh.sh_name as usize);
-                // Parse the byte until null termination
-                for &byte in it {
-                    if byte != b'\0' {
-                        ndx += 1;
-                    } else {
-                        break;
-                    }
-                }
-                // Parse the string from byte slice
-                if let Some(name) = core::str::from_utf8(
-                    strtab
-                        .get(
-                            (sh.sh_name as usize)
-                                ..((sh.sh_name as usize) + ndx),
-                        )
-                        .unwrap(),
-                )
-                .ok()
-                {
-                    Some(name)
-                } else {
-                    None
-                }
-            } else {
-                None
+    /// Build the `SectionNameIndex` of all the named sections in one pass
+    /// so the sections can be found by name without a linear scan
+    #[cfg(feature = "alloc")]
+    pub fn build_section_index(&'a self) -> SectionNameIndex<'a> {
+        let mut index = SectionNameIndex::new();
+        for sh in self.section_iter() {
+            if let Some(name) = self.section_name(sh) {
+                index.insert(name, sh);
             }
-        } else {
-            None
         }
+        index
     }
 
-    /// Parse the elf file and populate the struct
-    pub fn parse(mut self) -> Result<Self> {
-        // Parse the elf header
-        self.file_header = self.file_header.parse(self.elf)?;
-
-        let mut sht: SectionHeader = SectionHeader::new();
-        if self.shtstrtab == None {
-            for section in self.section_iter() {
-                if section.sh_type == SectionType::ShtStrTab
-                    && self.file_header.e_shstrndx as usize == section.sh_ndx
-                {
-                    sht = section.clone();
-                    break;
-                }
-            }
+    /// Returns the section named `name` from a prebuilt `SectionNameIndex`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn find_section_fast<'idx>(
+        &self,
+        name: &str,
+        idx: &'idx SectionNameIndex<'a>,
+    ) -> Option<SectionHeader> {
+        idx.get(name)
+    }
+
+    /// Returns the first section with the specified `SectionType`
+    #[must_use]
+    pub fn find_section_by_type(
+        &'a self,
+        ty: SectionType,
+    ) -> Option<SectionHeader> {
+        self.section_iter().find(|section| section.sh_type == ty)
+    }
+
+    /// Returns an iterator over all the sections with the specified
+    /// `SectionType`
+    pub fn iter_sections_of_type(
+        &'a self,
+        ty: SectionType,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.section_iter().of_type(ty)
+    }
+
+    /// Returns an iterator over the `PtLoad` segments
+    pub fn iter_load_segments(
+        &'a self,
+    ) -> impl Iterator<Item = ProgramHeader> + 'a {
+        self.program_iter().of_type(ProgramType::PtLoad)
+    }
+
+    /// Returns an iterator over the `PtNote` segments
+    pub fn iter_note_segments(
+        &'a self,
+    ) -> impl Iterator<Item = ProgramHeader> + 'a {
+        self.program_iter().of_type(ProgramType::PtNote)
+    }
+
+    /// Returns an iterator over the `PtDynamic` segments
+    pub fn iter_dynamic_segments(
+        &'a self,
+    ) -> impl Iterator<Item = ProgramHeader> + 'a {
+        self.program_iter().of_type(ProgramType::PtDynamic)
+    }
+
+    /// Returns an iterator over the `PtGnuRelro` segments
+    pub fn iter_gnu_relro_segments(
+        &'a self,
+    ) -> impl Iterator<Item = ProgramHeader> + 'a {
+        self.program_iter().of_type(ProgramType::PtGnuRelro)
+    }
+
+    /// Returns the `SymTabIterator` over the entries of a `ShtSymTab` or
+    /// `ShtDynSym` section
+    #[must_use]
+    pub fn symtab_iter(&'a self, sh: SectionHeader) -> SymTabIterator<'a> {
+        SymTabIterator::new(
+            sh,
+            self.file_header.e_class,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns the `SymTabIterator` over the first `ShtDynSym` section
+    #[must_use]
+    pub fn dynsym_iter(&'a self) -> Option<SymTabIterator<'a>> {
+        let dynsym = self.find_section_by_type(SectionType::ShtDynSym)?;
+        Some(self.symtab_iter(dynsym))
+    }
+
+    /// Returns the dynamic string table linked to the first `ShtDynSym`
+    /// section which holds the dynamic symbol names
+    #[must_use]
+    pub fn dynsym_strtab(&'a self) -> Option<SectionHeader> {
+        let dynsym = self.find_section_by_type(SectionType::ShtDynSym)?;
+        self.linked_section(&dynsym).ok()
+    }
+
+    /// Returns the `RelaIterator` over the entries of a `ShtRela` section
+    #[must_use]
+    pub fn rela_iter(&'a self, sh: SectionHeader) -> RelaIterator<'a> {
+        RelaIterator::new(
+            sh,
+            self.file_header.e_class,
+            self.file_header.e_machine,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns the `RelIterator` over the entries of a `ShtRel` section
+    #[must_use]
+    pub fn rel_iter(&'a self, sh: SectionHeader) -> RelIterator<'a> {
+        RelIterator::new(
+            sh,
+            self.file_header.e_class,
+            self.file_header.e_machine,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns the `RelrIterator` over the relocated addresses of a
+    /// `ShtRelr` section
+    #[must_use]
+    pub fn relr_iter(&'a self, sh: SectionHeader) -> RelrIterator<'a> {
+        RelrIterator::new(
+            sh,
+            self.file_header.e_class,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns an iterator over all the `ShtRela` sections
+    pub fn all_rela_sections(
+        &'a self,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.iter_sections_of_type(SectionType::ShtRela)
+    }
+
+    /// Returns an iterator over all the `ShtRel` sections
+    pub fn all_rel_sections(
+        &'a self,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.iter_sections_of_type(SectionType::ShtRel)
+    }
+
+    /// Returns an iterator over the entries of all the `ShtRela` sections
+    /// in the section header table order
+    pub fn all_rela_entries(&'a self) -> impl Iterator<Item = RelaEnt> + 'a {
+        self.all_rela_sections().flat_map(|sh| self.rela_iter(sh))
+    }
+
+    /// Returns an iterator over the entries of all the `ShtRel` sections in
+    /// the section header table order
+    pub fn all_rel_entries(&'a self) -> impl Iterator<Item = RelEnt> + 'a {
+        self.all_rel_sections().flat_map(|sh| self.rel_iter(sh))
+    }
+
+    /// Returns an iterator over the addresses of all the relative
+    /// relocations of a file loaded at `base` which is what a static PIE
+    /// needs to relocate itself
+    /// The addresses come from the `SHF_ALLOC` relocation sections and are
+    /// either `ShtRelr` entries or `R_*_RELATIVE` entries of the `ShtRela`
+    /// and `ShtRel` sections
+    /// The value to store is `base` plus the addend which is found in
+    /// place except for `ShtRela` entries which hold it in `r_addend`
+    /// Addresses which overflow when moved by `base` are skipped
+    pub fn static_pie_reloc_addresses(
+        &'a self,
+        base: u64,
+    ) -> impl Iterator<Item = u64> + 'a {
+        let relative = self.relative_reloc_type();
+        let sections = move |ty| {
+            self.section_iter()
+                .of_type(ty)
+                .filter(|sh| sh.sh_flags.is_alloc())
+        };
+
+        let relr = sections(SectionType::ShtRelr)
+            .flat_map(|sh| self.relr_iter(sh));
+        let rela = sections(SectionType::ShtRela)
+            .flat_map(|sh| self.rela_iter(sh))
+            .filter(move |rela| Some(rela.r_type()) == relative)
+            .map(|rela| rela.r_offset);
+        let rel = sections(SectionType::ShtRel)
+            .flat_map(|sh| self.rel_iter(sh))
+            .filter(move |rel| Some(rel.r_type()) == relative)
+            .map(|rel| rel.r_offset);
+
+        relr.chain(rela)
+            .chain(rel)
+            .filter_map(move |addr| addr.checked_add(base))
+    }
+
+    /// Returns the `R_*_RELATIVE` relocation type of the elf machine
+    fn relative_reloc_type(&self) -> Option<u32> {
+        let relative = match self.file_header.e_machine {
+            ElfMachine::Amd64 => x86_64::RelocType::Relative.raw_value(),
+            ElfMachine::Aarch64 => aarch64::RelocType::Relative.raw_value(),
+            ElfMachine::Arm => arm::RelocType::Relative.raw_value(),
+            ElfMachine::Riscv => riscv::RelocType::Relative.raw_value(),
+            ElfMachine::Intel80386 => relocation::R_386_RELATIVE,
+            _ => return None,
+        };
+        Some(relative)
+    }
+
+    /// Walk the relocations of the `ShtRela` section `rela_sh` for a file
+    /// loaded at `base` and call `apply` with the address to patch, the
+    /// relocation type, the symbol value and the addend
+    /// The symbols are resolved through `symtab` where undefined symbols
+    /// have the value zero and absolute and thread local symbols are not
+    /// moved by `base`
+    /// Every relocation is resolved before the first call to `apply` so an
+    /// error leaves none of them applied
+    pub fn apply_rela_callback<F>(
+        &'a self,
+        rela_sh: SectionHeader,
+        symtab: SectionHeader,
+        base: u64,
+        mut apply: F,
+    ) -> Result<()>
+    where
+        F: FnMut(u64, u32, u64, i64),
+    {
+        let relocations = self.rela_iter(rela_sh);
+        let total = relocations.len();
+        let mut resolved = 0;
+        for rela in self.rela_iter(rela_sh) {
+            let (sym, offset) = (rela.r_sym(), rela.r_offset);
+            self.resolve_relocation(symtab, sym, offset, base)?;
+            resolved += 1;
         }
-        self.shtstrtab = Some(sht);
 
-        Ok(self)
+        // A malformed entry ends the iteration before the last relocation
+        if resolved != total {
+            return Err(Error::TruncatedElf);
+        }
+
+        for rela in relocations {
+            let (offset, value) = self.resolve_relocation(
+                symtab,
+                rela.r_sym(),
+                rela.r_offset,
+                base,
+            )?;
+            apply(offset, rela.r_type(), value, rela.r_addend);
+        }
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    extern crate std;
-    use super::*;
-    use std::println;
+    /// Walk the relocations of the `ShtRel` section `rel_sh` for a file
+    /// loaded at `base` and call `apply` with the address to patch, the
+    /// relocation type and the symbol value
+    /// The addend is stored at the patched address and the symbols are
+    /// resolved like in `apply_rela_callback`
+    /// Every relocation is resolved before the first call to `apply` so an
+    /// error leaves none of them applied
+    pub fn apply_rel_callback<F>(
+        &'a self,
+        rel_sh: SectionHeader,
+        symtab: SectionHeader,
+        base: u64,
+        mut apply: F,
+    ) -> Result<()>
+    where
+        F: FnMut(u64, u32, u64),
+    {
+        let relocations = self.rel_iter(rel_sh);
+        let total = relocations.len();
+        let mut resolved = 0;
+        for rel in self.rel_iter(rel_sh) {
+            let (sym, offset) = (rel.r_sym(), rel.r_offset);
+            self.resolve_relocation(symtab, sym, offset, base)?;
+            resolved += 1;
+        }
 
-    #[test]
-    fn parse_elf32() {
-        let file = std::fs::read("./tests/elf_test32")
-            .expect("no file was found in the test location");
-        let e = Elf::new(file.as_slice());
-        let e = e.parse().unwrap();
-        for program in e.program_iter() {
-            println!("{:#x?}", program);
+        // A malformed entry ends the iteration before the last relocation
+        if resolved != total {
+            return Err(Error::TruncatedElf);
         }
-        for section in e.section_iter() {
-            println!("{:#x?}", section);
+
+        for rel in relocations {
+            let (offset, value) = self.resolve_relocation(
+                symtab,
+                rel.r_sym(),
+                rel.r_offset,
+                base,
+            )?;
+            apply(offset, rel.r_type(), value);
         }
-        println!("{:#x?}", e);
+
+        Ok(())
     }
 
-    #[test]
-    #[cfg(target_pointer_width = "64")]
-    fn parse_elf64() {
-        let file = std::fs::read("./tests/elf_test64")
-            .expect("no file was found in the test location");
-        let e = Elf::new(file.as_slice());
-        let e = e.parse().unwrap();
-        for program in e.program_iter() {
-            println!("{:#x?}", program);
+    /// Returns the address to patch and the symbol value of a relocation
+    /// at `r_offset` against the symbol at `ndx` in `symtab` for a file
+    /// loaded at `base`
+    fn resolve_relocation(
+        &'a self,
+        symtab: SectionHeader,
+        ndx: u32,
+        r_offset: u64,
+        base: u64,
+    ) -> Result<(u64, u64)> {
+        let value = self.relocation_symbol(symtab, ndx, base)?;
+        let offset = r_offset.checked_add(base).ok_or(Error::Overflow)?;
+        Ok((offset, value))
+    }
+
+    /// Returns the value of the symbol at `ndx` in `symtab` for a file
+    /// loaded at `base`
+    fn relocation_symbol(
+        &'a self,
+        symtab: SectionHeader,
+        ndx: u32,
+        base: u64,
+    ) -> Result<u64> {
+        // The null symbol is used by relocations without a symbol
+        if ndx == 0 {
+            return Ok(0);
         }
-        for section in e.section_iter() {
-            println!("{:#x?}", section);
+
+        let sym = self
+            .symtab_iter(symtab)
+            .nth(ndx as usize)
+            .ok_or(Error::BadElf)?;
+
+        // Thread local symbols hold an offset into the TLS block which does
+        // not depend on the load address
+        if sym.st_info == SymType::Tls {
+            return Ok(sym.st_value);
         }
-        println!("{:#x?}", e);
+
+        match sym.section_index() {
+            SectionIndex::Undef => Ok(0),
+            SectionIndex::Abs => Ok(sym.st_value),
+            _ => sym.st_value.checked_add(base).ok_or(Error::Overflow),
+        }
+    }
+
+    /// Returns the `DynIterator` over the entries of a `ShtDynamic` section
+    #[must_use]
+    pub fn dyn_iter(&'a self, sh: SectionHeader) -> DynIterator<'a> {
+        DynIterator::new(
+            sh,
+            self.file_header.e_class,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns the `DynIterator` over the entries of a `PtDynamic` segment
+    /// Stripped binaries might not have a `ShtDynamic` section
+    #[must_use]
+    pub fn dyn_iter_from_segment(
+        &'a self,
+        ph: &ProgramHeader,
+    ) -> DynIterator<'a> {
+        // Describe the segment as a section of address sized tag and value
+        let sh = SectionHeader {
+            sh_type: SectionType::ShtDynamic,
+            sh_offset: ph.p_offset,
+            sh_size: ph.p_filesz,
+            sh_entsize: self.pointer_size() as u64 * 2,
+            ..SectionHeader::new()
+        };
+
+        self.dyn_iter(sh)
+    }
+
+    /// Returns the `NoteIterator` over the notes of a `ShtNotes` section
+    #[must_use]
+    pub fn note_iter(&'a self, sh: &SectionHeader) -> NoteIterator<'a> {
+        NoteIterator::new(
+            sh.sh_offset,
+            sh.sh_size,
+            sh.sh_addralign,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns the `NoteIterator` over the notes of a `PtNote` segment
+    /// Stripped binaries might only keep their notes in the segments
+    #[must_use]
+    pub fn note_iter_from_segment(
+        &'a self,
+        ph: &ProgramHeader,
+    ) -> NoteIterator<'a> {
+        NoteIterator::new(
+            ph.p_offset,
+            ph.p_filesz,
+            ph.p_align,
+            self.file_header.e_data,
+            self.elf,
+        )
+    }
+
+    /// Returns an iterator over the notes of the `PtNote` segments of a
+    /// core file classified by their type
+    /// Returns `None` if the file is not a core file
+    #[must_use]
+    pub fn core_dump_notes(
+        &'a self,
+    ) -> Option<impl Iterator<Item = CoreNote<'a>> + 'a> {
+        if self.file_header.e_type != ElfType::CoreFile {
+            return None;
+        }
+        Some(
+            self.iter_note_segments()
+                .flat_map(|ph| self.note_iter_from_segment(&ph))
+                .map(CoreNote::from),
+        )
+    }
+
+    /// Returns the `VersionSymIterator` over the entries of a
+    /// `ShtGnuVerSym` section which are parallel to the dynamic symbols
+    #[must_use]
+    pub fn versym_iter(&'a self, sh: SectionHeader) -> VersionSymIterator<'a> {
+        VersionSymIterator::new(sh, self.file_header.e_data, self.elf)
+    }
+
+    /// Returns the `VersionNeedIterator` over the entries of a
+    /// `ShtGnuVerNeed` section
+    #[must_use]
+    pub fn verneed_iter(
+        &'a self,
+        sh: SectionHeader,
+    ) -> VersionNeedIterator<'a> {
+        VersionNeedIterator::new(sh, self.file_header.e_data, self.elf)
+    }
+
+    /// Returns the `VersionDefIterator` over the entries of a
+    /// `ShtGnuVerDef` section
+    #[must_use]
+    pub fn verdef_iter(&'a self, sh: SectionHeader) -> VersionDefIterator<'a> {
+        VersionDefIterator::new(sh, self.file_header.e_data, self.elf)
+    }
+
+    /// Returns the version name like `GLIBC_2.2.5` of the dynamic symbol at
+    /// `sym_ndx` from the `ShtGnuVerSym` section
+    /// The local and the unversioned global symbols have no version name
+    #[must_use]
+    pub fn gnu_version_for_symbol(
+        &'a self,
+        sym_ndx: usize,
+    ) -> Option<&'a str> {
+        let versym = self.find_section_by_type(SectionType::ShtGnuVerSym)?;
+        let ndx = self.versym_iter(versym).nth(sym_ndx)?.version_index();
+        if ndx == VER_NDX_LOCAL || ndx == VER_NDX_GLOBAL {
+            return None;
+        }
+
+        // The index is either defined by this file or needed from one of
+        // its dependencies and the hidden bit does not tell which one
+        let defined = self
+            .find_section_by_type(SectionType::ShtGnuVerDef)
+            .and_then(|sh| {
+                let def = self.verdef_iter(sh).find(|def| def.vd_ndx == ndx)?;
+                let aux = def.aux_iter().next()?;
+                Some((sh, aux.vda_name))
+            });
+        let (sh, name) = defined.or_else(|| {
+            let sh = self.find_section_by_type(SectionType::ShtGnuVerNeed)?;
+            let aux = self
+                .verneed_iter(sh)
+                .flat_map(|need| need.aux_iter())
+                .find(|aux| aux.vna_other == ndx)?;
+            Some((sh, aux.vna_name))
+        })?;
+
+        // The names are in the string table linked to the version section
+        let strtab = self.linked_section(&sh).ok()?;
+        self.ndx_name(name as usize, &strtab)
+    }
+
+    /// Returns the GNU build-id bytes without copying them
+    /// `PtNote` segments are searched first then the `ShtNotes` sections
+    #[must_use]
+    pub fn gnu_build_id(&'a self) -> Option<&'a [u8]> {
+        self.gnu_note(NT_GNU_BUILD_ID)
+    }
+
+    /// Returns the `GnuDebugLink` of the `.gnu_debuglink` section which
+    /// names the separate debug file and its CRC32
+    #[must_use]
+    pub fn gnu_debuglink(&'a self) -> Option<GnuDebugLink<'a>> {
+        let sh = self.find_section(".gnu_debuglink")?;
+        let data = self.get_section(sh).ok()?;
+        GnuDebugLink::parse(data, self.file_header.e_data).ok()
+    }
+
+    /// Returns the `GnuBuildAttrIterator` over the notes of the
+    /// `.gnu.build.attributes` section written by the annobin plugin
+    #[must_use]
+    pub fn gnu_build_attributes(&'a self) -> Option<GnuBuildAttrIterator<'a>> {
+        let sh = self.find_section(".gnu.build.attributes")?;
+        Some(GnuBuildAttrIterator::new(
+            self.note_iter(&sh),
+            self.file_header.e_class,
+            self.file_header.e_data,
+        ))
+    }
+
+    /// Returns the `ModinfoIterator` over the `key=value` pairs of the
+    /// `.modinfo` section of a Linux kernel module
+    #[must_use]
+    pub fn kernel_module_info(&'a self) -> Option<ModinfoIterator<'a>> {
+        let sh = self.find_section(".modinfo")?;
+        Some(ModinfoIterator::new(self.get_section(sh).ok()?))
+    }
+
+    /// Returns true if the file is a Linux kernel module which is a
+    /// relocatable object with a `.modinfo` section
+    /// The kernel image itself also has a `.modinfo` section for its
+    /// built-in modules
+    #[inline]
+    #[must_use]
+    pub fn is_kernel_module(&'a self) -> bool {
+        self.is_relocatable_object() && self.find_section(".modinfo").is_some()
+    }
+
+    /// Returns the `GnuPropertyIterator` over the program properties
+    /// The properties are found in the `PtGnuProperty` or `PtNote` segments
+    /// then in the `ShtNotes` sections like `.note.gnu.property`
+    #[must_use]
+    pub fn gnu_properties(&'a self) -> Option<GnuPropertyIterator<'a>> {
+        let desc = self.gnu_note(NT_GNU_PROPERTY_TYPE_0)?;
+        Some(GnuPropertyIterator::new(
+            desc,
+            self.file_header.e_class,
+            self.file_header.e_data,
+        ))
+    }
+
+    /// Returns the descriptor of the first GNU note of type `n_type`
+    fn gnu_note(&'a self, n_type: u32) -> Option<&'a [u8]> {
+        let segments = self
+            .program_iter()
+            .filter(|ph| {
+                matches!(
+                    ph.p_type,
+                    ProgramType::PtGnuProperty | ProgramType::PtNote
+                )
+            })
+            .flat_map(|ph| self.note_iter_from_segment(&ph));
+        let sections = self
+            .iter_sections_of_type(SectionType::ShtNotes)
+            .flat_map(|sh| self.note_iter(&sh));
+
+        segments
+            .chain(sections)
+            .find(|note| note.n_type == n_type && note.name == ELF_NOTE_GNU)
+            .map(|note| note.desc)
+    }
+
+    /// Returns the `TlsTemplate` of the `PtTls` segment which a runtime
+    /// copies into the thread-local storage of every thread
+    #[must_use]
+    pub fn tls_template(&'a self) -> Option<TlsTemplate<'a>> {
+        let ph = self.program_iter().of_type(ProgramType::PtTls).next()?;
+        let zero_size = ph.p_memsz.checked_sub(ph.p_filesz)?;
+        Some(TlsTemplate {
+            data: self.get_segment_data(&ph).ok()?,
+            zero_size: usize::try_from(zero_size).ok()?,
+            align: usize::try_from(ph.p_align).ok()?,
+        })
+    }
+
+    /// Returns the size of a lazy binding stub of the `.plt` section for
+    /// the elf machine
+    #[must_use]
+    pub fn plt_entry_size(&self) -> Option<usize> {
+        match self.file_header.e_machine {
+            ElfMachine::Amd64 | ElfMachine::Intel80386 => Some(16),
+            ElfMachine::Aarch64 | ElfMachine::Riscv => Some(16),
+            ElfMachine::Arm => Some(12),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of the `.plt` header calling the dynamic linker
+    /// which comes before the stubs
+    fn plt_header_size(&self) -> Option<usize> {
+        match self.file_header.e_machine {
+            ElfMachine::Amd64 | ElfMachine::Intel80386 => Some(16),
+            ElfMachine::Aarch64 | ElfMachine::Riscv => Some(32),
+            ElfMachine::Arm => Some(20),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the index and the file offset of every
+    /// stub of the `.plt` section after its header
+    #[must_use]
+    pub fn plt_entries_iter(
+        &'a self,
+    ) -> Option<impl Iterator<Item = (usize, u64)> + 'a> {
+        let sh = self.find_section(".plt")?;
+        let entry = self.plt_entry_size()?;
+        let header = self.plt_header_size()?;
+        let size = usize::try_from(sh.sh_size).ok()?;
+        let count = size.saturating_sub(header) / entry;
+        Some((0..count).map(move |ndx| {
+            let offset = (header + ndx * entry) as u64;
+            (ndx, sh.sh_offset.wrapping_add(offset))
+        }))
+    }
+
+    /// Returns the `.text` section holding the executable code
+    #[must_use]
+    pub fn text_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".text")
+    }
+
+    /// Returns the `.data` section holding the initialized writable data
+    #[must_use]
+    pub fn data_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".data")
+    }
+
+    /// Returns the `.bss` section holding the zero initialized data
+    #[must_use]
+    pub fn bss_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".bss")
+    }
+
+    /// Returns the `.rodata` section holding the read-only data
+    #[must_use]
+    pub fn rodata_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".rodata")
+    }
+
+    /// Returns the contents of the `.text` section
+    pub fn text_data(&'a self) -> Result<&'a [u8]> {
+        self.named_section_data(".text")
+    }
+
+    /// Returns the contents of the `.data` section
+    pub fn data_data(&'a self) -> Result<&'a [u8]> {
+        self.named_section_data(".data")
+    }
+
+    /// Returns the contents of the `.bss` section which is always empty as
+    /// it takes no space in the file
+    pub fn bss_data(&'a self) -> Result<&'a [u8]> {
+        self.named_section_data(".bss")
+    }
+
+    /// Returns the contents of the `.rodata` section
+    pub fn rodata_data(&'a self) -> Result<&'a [u8]> {
+        self.named_section_data(".rodata")
+    }
+
+    /// Returns the contents of the section named `name` where the
+    /// `ShtNoBits` sections have no contents in the file
+    fn named_section_data(&'a self, name: &str) -> Result<&'a [u8]> {
+        let sh = self.find_section(name).ok_or(Error::SectionNotFound)?;
+        if sh.sh_type == SectionType::ShtNoBits {
+            return Ok(&[]);
+        }
+        self.get_section(sh)
+    }
+
+    /// Returns the `.got` section holding the addresses of the data and
+    /// the eagerly bound functions
+    #[must_use]
+    pub fn got_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".got")
+    }
+
+    /// Returns the `.got.plt` section holding the addresses of the lazily
+    /// bound functions
+    #[must_use]
+    pub fn got_plt_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".got.plt")
+    }
+
+    /// Returns an iterator over the address sized entries of the `got`
+    /// section as stored in the file before any relocation
+    pub fn got_entries_iter(
+        &'a self,
+        got: &SectionHeader,
+    ) -> impl Iterator<Item = u64> + 'a {
+        let class = self.file_header.e_class;
+        let data = self.file_header.e_data;
+        // A missing or unreadable section has no entries
+        let bytes = self.get_section(*got).unwrap_or(&[]);
+        bytes.chunks_exact(self.pointer_size()).map(move |entry| {
+            if class == ElfClass::Class32 {
+                u32::endian_parse(0x00..0x04, entry, &data).unwrap_or(0) as u64
+            } else {
+                u64::endian_parse(0x00..0x08, entry, &data).unwrap_or(0)
+            }
+        })
+    }
+
+    /// Returns the program interpreter path of the `PtInterp` segment
+    /// Statically linked files have no program interpreter
+    #[must_use]
+    pub fn get_interp(&'a self) -> Option<&'a str> {
+        let ph = self.program_iter().of_type(ProgramType::PtInterp).next()?;
+        let data = self.get_segment_data(&ph).ok()?;
+        // Strip the null terminator
+        let data = data.strip_suffix(b"\0").unwrap_or(data);
+        core::str::from_utf8(data).ok()
+    }
+
+    /// Returns an iterator over the names of the needed libraries from the
+    /// `Needed` entries of the dynamic section
+    pub fn needed_libraries(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        self.dynamic_strtab()
+            .into_iter()
+            .flat_map(|(entries, strtab)| {
+                entries
+                    .filter(|entry| entry.d_tag == DynTag::Needed)
+                    .filter_map(move |entry| str_at(strtab, entry.d_val))
+            })
+    }
+
+    /// Returns the shared object name of the `SoName` dynamic entry
+    #[must_use]
+    pub fn soname(&'a self) -> Option<&'a str> {
+        self.dyn_string(DynTag::SoName)
+    }
+
+    /// Returns the library search path of the `RunPath` dynamic entry
+    #[must_use]
+    pub fn runpath(&'a self) -> Option<&'a str> {
+        self.dyn_string(DynTag::RunPath)
+    }
+
+    /// Returns the deprecated library search path of the `RPath` dynamic
+    /// entry
+    #[must_use]
+    pub fn rpath(&'a self) -> Option<&'a str> {
+        self.dyn_string(DynTag::RPath)
+    }
+
+    /// Returns the dynamic string of the first dynamic entry with `tag`
+    fn dyn_string(&'a self, tag: DynTag) -> Option<&'a str> {
+        let (mut entries, strtab) = self.dynamic_strtab()?;
+        let entry = entries.find(|entry| entry.d_tag == tag)?;
+        str_at(strtab, entry.d_val)
+    }
+
+    /// Returns the entries of the `ShtDynamic` section or else of the
+    /// `PtDynamic` segment
+    fn dynamic_entries(&'a self) -> Option<DynIterator<'a>> {
+        if let Some(sh) = self.find_section_by_type(SectionType::ShtDynamic) {
+            return Some(self.dyn_iter(sh));
+        }
+        let ph = self.iter_dynamic_segments().next()?;
+        Some(self.dyn_iter_from_segment(&ph))
+    }
+
+    /// Returns the value of the first dynamic entry with `tag`
+    fn dyn_value(&'a self, tag: DynTag) -> Option<u64> {
+        self.dynamic_entries()?
+            .find(|entry| entry.d_tag == tag)
+            .map(|entry| entry.d_val)
+    }
+
+    /// Returns the dynamic entries and the dynamic string table data
+    /// The `ShtDynamic` section is used first then the `PtDynamic` segment
+    fn dynamic_strtab(&'a self) -> Option<(DynIterator<'a>, &'a [u8])> {
+        if let Some(sh) = self.find_section_by_type(SectionType::ShtDynamic) {
+            let strtab = self.section_at_index(sh.sh_link as usize).ok()?;
+            return Some((self.dyn_iter(sh), self.get_section(strtab).ok()?));
+        }
+
+        // Locate the string table through its address in the entries
+        let ph = self.iter_dynamic_segments().next()?;
+        let entries = self.dyn_iter_from_segment(&ph);
+        let value = |tag| {
+            let mut entries = entries;
+            entries.find(|entry| entry.d_tag == tag).map(|entry| entry.d_val)
+        };
+        let start = self.vaddr_to_file_offset(value(DynTag::StrTab)?)?;
+        let end = start.checked_add(value(DynTag::StrSz)?)?;
+        let strtab = self.elf.get(
+            usize::try_from(start).ok()?..usize::try_from(end).ok()?,
+        )?;
+
+        Some((entries, strtab))
+    }
+
+    /// Returns true if the file is of the 64-bit class
+    #[inline]
+    #[must_use]
+    pub fn is_64bit(&self) -> bool {
+        self.file_header.e_class == ElfClass::Class64
+    }
+
+    /// Returns true if the file is of the 32-bit class
+    #[inline]
+    #[must_use]
+    pub fn is_32bit(&self) -> bool {
+        self.file_header.e_class == ElfClass::Class32
+    }
+
+    /// Returns true if the file data is encoded in little endian
+    #[inline]
+    #[must_use]
+    pub fn is_little_endian(&self) -> bool {
+        self.file_header.e_data == ElfData::ElfData2Lsb
+    }
+
+    /// Returns true if the file data is encoded in big endian
+    #[inline]
+    #[must_use]
+    pub fn is_big_endian(&self) -> bool {
+        self.file_header.e_data == ElfData::ElfData2Msb
+    }
+
+    /// Returns the size in bytes of an address of the file class
+    pub fn pointer_size(&self) -> usize {
+        if self.is_32bit() { 0x04 } else { 0x08 }
+    }
+
+    /// Returns true if the file is a position independent executable
+    /// Shared objects are told apart by the `DF_1_PIE` flag or the program
+    /// interpreter which only executables request
+    #[inline]
+    #[must_use]
+    pub fn is_pie(&'a self) -> bool {
+        self.file_header.e_type == ElfType::SharedObject
+            && (self
+                .dyn_value(DynTag::Flags1)
+                .is_some_and(|flags| flags & DF_1_PIE != 0)
+                || self.get_interp().is_some())
+    }
+
+    /// Returns true if the file has a dynamic section or segment
+    #[inline]
+    #[must_use]
+    pub fn is_dynamically_linked(&'a self) -> bool {
+        self.dynamic_entries().is_some()
+    }
+
+    /// Returns true if the file has no dynamic section or segment
+    #[inline]
+    #[must_use]
+    pub fn is_statically_linked(&'a self) -> bool {
+        !self.is_dynamically_linked()
+    }
+
+    /// Returns true if the file is an executable or a shared object with an
+    /// entry point like position independent executables
+    #[inline]
+    #[must_use]
+    pub fn is_executable(&self) -> bool {
+        match self.file_header.e_type {
+            ElfType::Executable => true,
+            ElfType::SharedObject => self.file_header.e_entry != 0,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the file is a shared object without an entry point
+    #[inline]
+    #[must_use]
+    pub fn is_shared_library(&self) -> bool {
+        self.file_header.e_type == ElfType::SharedObject
+            && self.file_header.e_entry == 0
+    }
+
+    /// Returns true if the file is a relocatable object file
+    #[inline]
+    #[must_use]
+    pub fn is_relocatable(&self) -> bool {
+        self.file_header.e_type == ElfType::Relocatable
+    }
+
+    /// Returns true if the file is a relocatable object file
+    /// Same as `is_relocatable`
+    #[inline]
+    #[must_use]
+    pub fn is_relocatable_object(&self) -> bool {
+        self.is_relocatable()
+    }
+
+    /// Returns true if the file is a core dump
+    #[inline]
+    #[must_use]
+    pub fn is_core_dump(&self) -> bool {
+        self.file_header.e_type == ElfType::CoreFile
+    }
+
+    /// Returns true if the `PtGnuStack` segment marks the stack as not
+    /// executable
+    /// Without the segment the stack is assumed to be executable
+    #[must_use]
+    pub fn has_nx_stack(&'a self) -> bool {
+        self.program_iter()
+            .of_type(ProgramType::PtGnuStack)
+            .next()
+            .is_some_and(|ph| !ph.p_flags.is_exec())
+    }
+
+    /// Returns true if the file has a `PtGnuRelro` segment
+    #[must_use]
+    pub fn has_relro(&'a self) -> bool {
+        self.iter_gnu_relro_segments().next().is_some()
+    }
+
+    /// Returns true if the relro segment is present and all the relocations
+    /// are processed at load time so the GOT can be made read-only
+    #[must_use]
+    pub fn has_full_relro(&'a self) -> bool {
+        let Some(mut entries) = self.dynamic_entries() else {
+            return false;
+        };
+        let bind_now = entries.any(|entry| match entry.d_tag {
+            DynTag::BindNow => true,
+            DynTag::Flags => entry.d_val & DF_BIND_NOW != 0,
+            DynTag::Flags1 => entry.d_val & DF_1_NOW != 0,
+            _ => false,
+        });
+
+        self.has_relro() && bind_now
+    }
+
+    /// Returns true if the file has no `ShtSymTab` section
+    #[inline]
+    #[must_use]
+    pub fn is_stripped(&'a self) -> bool {
+        self.find_section_by_type(SectionType::ShtSymTab).is_none()
+    }
+
+    /// Returns an iterator over the DWARF sections whose names start with
+    /// `.debug`
+    pub fn debug_sections(
+        &'a self,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.section_iter().filter(|&sh| {
+            self.section_name_bytes(sh)
+                .is_some_and(|name| name.starts_with(b".debug"))
+        })
+    }
+
+    /// Returns the `.debug_info` section holding the DWARF debug entries
+    #[must_use]
+    pub fn debug_info_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".debug_info")
+    }
+
+    /// Returns the `.debug_abbrev` section holding the DWARF abbreviations
+    #[must_use]
+    pub fn debug_abbrev_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".debug_abbrev")
+    }
+
+    /// Returns the `.debug_line` section holding the DWARF line programs
+    #[must_use]
+    pub fn debug_line_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".debug_line")
+    }
+
+    /// Returns the `.debug_str` section holding the DWARF strings
+    #[must_use]
+    pub fn debug_str_section(&'a self) -> Option<SectionHeader> {
+        self.find_section(".debug_str")
+    }
+
+    /// Returns true if the file has a `.debug_info` section
+    #[must_use]
+    pub fn has_debug_info(&'a self) -> bool {
+        self.debug_info_section().is_some()
+    }
+
+    /// Returns the first `ShtGnuHash` section holding the GNU hash table
+    #[must_use]
+    pub fn gnu_hash_section(&'a self) -> Option<SectionHeader> {
+        self.find_section_by_type(SectionType::ShtGnuHash)
+    }
+
+    /// Returns the first `ShtHash` section holding the SysV hash table
+    #[must_use]
+    pub fn sysv_hash_section(&'a self) -> Option<SectionHeader> {
+        self.find_section_by_type(SectionType::ShtHash)
+    }
+
+    /// Returns the `GnuHashTable` of the `ShtGnuHash` section
+    #[must_use]
+    pub fn gnu_hash_table(&'a self) -> Option<GnuHashTable<'a>> {
+        let sh = self.gnu_hash_section()?;
+        GnuHashTable::parse(
+            self.get_section(sh).ok()?,
+            self.file_header.e_class,
+            self.file_header.e_data,
+        )
+        .ok()
+    }
+
+    /// Returns the `SysVHashTable` of the `ShtHash` section
+    #[must_use]
+    pub fn sysv_hash_table(&'a self) -> Option<SysVHashTable<'a>> {
+        let sh = self.sysv_hash_section()?;
+        SysVHashTable::parse(
+            self.get_section(sh).ok()?,
+            self.file_header.e_data,
+        )
+        .ok()
+    }
+
+    /// Returns the `EhFrameHdr` of the `.eh_frame_hdr` section or of the
+    /// `PtGnuEhFrame` segment when the section headers are stripped
+    #[must_use]
+    pub fn eh_frame_hdr(&'a self) -> Option<EhFrameHdr<'a>> {
+        let (data, addr) = match self.find_section(".eh_frame_hdr") {
+            Some(sh) => (self.get_section(sh).ok()?, sh.sh_addr),
+            None => {
+                let ph = self
+                    .program_iter()
+                    .find(|ph| ph.p_type == ProgramType::PtGnuEhFrame)?;
+                (self.get_segment_data(&ph).ok()?, ph.p_vaddr)
+            }
+        };
+        EhFrameHdr::parse(
+            data,
+            addr,
+            self.file_header.e_class,
+            self.file_header.e_data,
+        )
+        .ok()
+    }
+
+    /// Translate the virtual address `vaddr` to its offset in the elf file
+    /// using the `PtLoad` segment whose file image contains it
+    #[must_use]
+    pub fn vaddr_to_file_offset(&'a self, vaddr: u64) -> Option<u64> {
+        self.iter_load_segments()
+            // Addresses of the memory only part have no file offset
+            .find(|ph| contains(ph.p_vaddr, ph.p_filesz, vaddr))
+            .and_then(|ph| ph.p_offset.checked_add(vaddr - ph.p_vaddr))
+    }
+
+    /// Translate the elf file `offset` to its virtual address using the
+    /// `PtLoad` segment whose file image contains it
+    #[must_use]
+    pub fn file_offset_to_vaddr(&'a self, offset: u64) -> Option<u64> {
+        self.iter_load_segments()
+            .find(|ph| ph.contains_file_offset(offset))
+            .and_then(|ph| ph.p_vaddr.checked_add(offset - ph.p_offset))
+    }
+
+    /// Returns the first `PtLoad` segment whose memory image contains the
+    /// virtual address `addr`
+    #[must_use]
+    pub fn segment_containing_vaddr(
+        &'a self,
+        addr: u64,
+    ) -> Option<ProgramHeader> {
+        self.iter_load_segments().find(|ph| ph.contains_vaddr(addr))
+    }
+
+    /// Returns the first `SHF_ALLOC` section whose address range contains
+    /// the virtual address `addr`
+    #[must_use]
+    pub fn section_containing_vaddr(
+        &'a self,
+        addr: u64,
+    ) -> Option<SectionHeader> {
+        self.section_iter()
+            .of_flags(SectionFlags::ALLOC)
+            .find(|sh| sh.contains_vaddr(addr))
+    }
+
+    /// Returns the lowest virtual address of the `PtLoad` segments
+    #[must_use]
+    pub fn min_load_address(&'a self) -> Option<u64> {
+        self.iter_load_segments().map(|ph| ph.p_vaddr).min()
+    }
+
+    /// Returns the end virtual address of the highest `PtLoad` segment
+    /// including its memory only part
+    #[must_use]
+    pub fn max_load_address(&'a self) -> Option<u64> {
+        self.iter_load_segments()
+            .map(|ph| ph.p_vaddr.checked_add(ph.p_memsz))
+            // An overflowing segment end makes the whole result unknown
+            .try_fold(None, |max: Option<u64>, end| Some(max.max(Some(end?))))?
+    }
+
+    /// Returns the size of the memory span of the `PtLoad` segments aligned
+    /// to their biggest alignment
+    #[must_use]
+    pub fn image_size(&'a self) -> Option<u64> {
+        let align = self.iter_load_segments().map(|ph| ph.p_align).max()?;
+        let size = self
+            .max_load_address()?
+            .checked_sub(self.min_load_address()?)?;
+        size.checked_next_multiple_of(align.max(1))
+    }
+
+    /// Returns the sum of the memory sizes of the `PtLoad` segments
+    /// The gaps between the segments are not counted unlike `image_size`
+    pub fn total_load_size(&'a self) -> u64 {
+        self.iter_load_segments()
+            .fold(0, |total, ph| total.saturating_add(ph.p_memsz))
+    }
+
+    /// Returns the `PtLoad` segment containing the entry point
+    /// A zero `e_entry` means the file has no entry point
+    #[must_use]
+    pub fn entry_point_segment(&'a self) -> Option<ProgramHeader> {
+        match self.file_header.e_entry {
+            0 => None,
+            entry => self.segment_containing_vaddr(entry),
+        }
+    }
+
+    /// Returns the `SHF_ALLOC` section containing the entry point
+    /// A zero `e_entry` means the file has no entry point
+    #[must_use]
+    pub fn entry_point_section(&'a self) -> Option<SectionHeader> {
+        match self.file_header.e_entry {
+            0 => None,
+            entry => self.section_containing_vaddr(entry),
+        }
+    }
+
+    /// Returns an iterator over the pairs of sections whose data overlap
+    /// in the file in the section header table order
+    /// `ShtNoBits` sections take no room in the file so they are skipped
+    pub fn overlapping_sections(
+        &'a self,
+    ) -> impl Iterator<Item = (SectionHeader, SectionHeader)> + 'a {
+        let sections = self.section_iter();
+        sections.enumerate().flat_map(move |(ndx, sh)| {
+            sections
+                .skip(ndx + 1)
+                .filter(move |other| sh.overlaps_file(other))
+                .map(move |other| (sh, other))
+        })
+    }
+
+    /// Returns an iterator over the pairs of `PtLoad` segments whose file
+    /// images overlap in the program header table order
+    pub fn overlapping_segments(
+        &'a self,
+    ) -> impl Iterator<Item = (ProgramHeader, ProgramHeader)> + 'a {
+        self.iter_load_segments()
+            .enumerate()
+            .flat_map(move |(ndx, ph)| {
+                self.iter_load_segments()
+                    .skip(ndx + 1)
+                    .filter(move |other| ph.overlaps_file(other))
+                    .map(move |other| (ph, other))
+            })
+    }
+
+    /// Returns true if the data of any two sections overlap in the file
+    #[must_use]
+    pub fn has_overlapping_sections(&'a self) -> bool {
+        self.overlapping_sections().next().is_some()
+    }
+
+    /// Returns true if the file images of any two `PtLoad` segments
+    /// overlap
+    #[must_use]
+    pub fn has_overlapping_segments(&'a self) -> bool {
+        self.overlapping_segments().next().is_some()
+    }
+
+    /// Returns an iterator over the sections whose file image is inside the
+    /// file image of the segment `ph`
+    /// `ShtNoBits` sections take no room in the file so they are skipped
+    pub fn sections_in_segment(
+        &'a self,
+        ph: &ProgramHeader,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        let ph = *ph;
+        self.section_iter().filter(move |sh| {
+            sh.sh_type != SectionType::ShtNoBits
+                && contains_range(
+                    ph.p_offset,
+                    ph.p_filesz,
+                    sh.sh_offset,
+                    sh.sh_size,
+                )
+        })
+    }
+
+    /// Returns an iterator over the allocated sections whose memory image
+    /// is inside the memory image of the segment `ph`
+    /// `ShtNoBits` sections are included since they take room in memory
+    pub fn sections_in_segment_vaddr(
+        &'a self,
+        ph: &ProgramHeader,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        let ph = *ph;
+        self.section_iter().filter(move |sh| {
+            sh.sh_flags.is_alloc()
+                && contains_range(
+                    ph.p_vaddr,
+                    ph.p_memsz,
+                    sh.sh_addr,
+                    sh.sh_size,
+                )
+        })
+    }
+
+    /// Returns the first symbol named `name` from the `ShtSymTab` section
+    /// and then from the `ShtDynSym` section
+    /// The dynamic symbols are looked up through the hash tables if present
+    #[must_use]
+    pub fn find_symbol(&'a self, name: &str) -> Option<SymTabEnt> {
+        // The static symbol table has no hash table so scan it
+        let symtab = self.find_section_by_type(SectionType::ShtSymTab);
+        if let Some(sym) = symtab
+            .and_then(|sh| self.symbols_named(sh, name).next())
+        {
+            return Some(sym);
+        }
+
+        self.find_dynamic_symbol(name).map(|(_, sym)| sym)
+    }
+
+    /// Returns the symbol named `name` with its name, section and GNU
+    /// version in a single lookup
+    /// The dynamic symbols are looked up first through the hash tables as
+    /// only they have a version and then the `ShtSymTab` section is scanned
+    #[must_use]
+    pub fn resolve_symbol(
+        &'a self,
+        name: &str,
+    ) -> Option<ResolvedSymbol<'a>> {
+        let dynamic = self.find_dynamic_symbol(name).and_then(|(ndx, _)| {
+            let dynsym = self.find_section_by_type(SectionType::ShtDynSym)?;
+            Some((ndx, dynsym))
+        });
+        let (ndx, symtab) = dynamic.or_else(|| {
+            let symtab = self.find_section_by_type(SectionType::ShtSymTab)?;
+            let strtab = self.linked_section(&symtab).ok()?;
+            let ndx = self
+                .symtab_iter(symtab)
+                .position(|sym| self.sym_name(sym, &strtab) == Some(name))?;
+            Some((ndx, symtab))
+        })?;
+
+        // Take the name from the string table so it lives as long as the
+        // file and not the query
+        let entry = self.symtab_iter(symtab).nth(ndx)?;
+        let strtab = self.linked_section(&symtab).ok()?;
+        let name = self.sym_name(entry, &strtab)?;
+
+        // Only the symbols defined in a section have one
+        let section = match entry.section_index() {
+            SectionIndex::Index(_) | SectionIndex::Xindex => self
+                .true_section_index(ndx, &symtab)
+                .ok()
+                .and_then(|shndx| self.section_at_index(shndx as usize).ok()),
+            _ => None,
+        };
+
+        // The version table is parallel to the dynamic symbols only
+        let version = if symtab.sh_type == SectionType::ShtDynSym {
+            self.gnu_version_for_symbol(ndx)
+        } else {
+            None
+        };
+
+        Some(ResolvedSymbol { entry, name, section, version })
+    }
+
+    /// Returns the dynamic symbol named `name` with its index using the
+    /// GNU hash table, then the SysV hash table and then a linear scan
+    fn find_dynamic_symbol(
+        &'a self,
+        name: &str,
+    ) -> Option<(usize, SymTabEnt)> {
+        let (symbols, dynstr) = self.dynamic_symbols()?;
+        if let Some(table) = self.gnu_hash_table() {
+            return GnuHashLookup::new(table, symbols, dynstr, self)
+                .lookup_index(name);
+        }
+        if let Some(table) = self.sysv_hash_table() {
+            return SysVHashLookup::new(table, symbols, dynstr, self)
+                .lookup_index(name);
+        }
+
+        LinearLookup::new(symbols, dynstr, self).lookup_index(name)
+    }
+
+    /// Returns the fastest `SymbolLookup` over the dynamic symbol table
+    /// which uses the GNU hash table, then the SysV hash table and then a
+    /// linear scan of the symbols
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn fastest_symbol_lookup(
+        &'a self,
+    ) -> Option<alloc::boxed::Box<dyn SymbolLookup + 'a>> {
+        let (symbols, dynstr) = self.dynamic_symbols()?;
+        if let Some(table) = self.gnu_hash_table() {
+            let lookup = GnuHashLookup::new(table, symbols, dynstr, self);
+            return Some(alloc::boxed::Box::new(lookup));
+        }
+        if let Some(table) = self.sysv_hash_table() {
+            let lookup = SysVHashLookup::new(table, symbols, dynstr, self);
+            return Some(alloc::boxed::Box::new(lookup));
+        }
+
+        let lookup = LinearLookup::new(symbols, dynstr, self);
+        Some(alloc::boxed::Box::new(lookup))
+    }
+
+    /// Returns the iterator over the first `ShtDynSym` section with the
+    /// string table found in its `sh_link`
+    fn dynamic_symbols(
+        &'a self,
+    ) -> Option<(SymTabIterator<'a>, SectionHeader)> {
+        let dynsym = self.find_section_by_type(SectionType::ShtDynSym)?;
+        let dynstr = self.section_at_index(dynsym.sh_link as usize).ok()?;
+        Some((self.symtab_iter(dynsym), dynstr))
+    }
+
+    /// Returns an iterator over all the symbols named `name` from the
+    /// `ShtSymTab` section and then from the `ShtDynSym` section
+    pub fn find_all_symbols(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = SymTabEnt> + 'a {
+        [SectionType::ShtSymTab, SectionType::ShtDynSym]
+            .into_iter()
+            .filter_map(move |ty| self.find_section_by_type(ty))
+            .flat_map(move |sh| self.symbols_named(sh, name))
+    }
+
+    /// Returns the function or object symbol from the `ShtSymTab` and
+    /// `ShtDynSym` sections whose range contains `vaddr`
+    /// Symbols without a size only match their exact address and the
+    /// symbol with the highest `st_value` wins when several match
+    #[must_use]
+    pub fn find_symbol_by_address(&'a self, vaddr: u64) -> Option<SymTabEnt> {
+        [SectionType::ShtSymTab, SectionType::ShtDynSym]
+            .into_iter()
+            .filter_map(|ty| self.find_section_by_type(ty))
+            .flat_map(|sh| self.symtab_iter(sh))
+            .filter(|sym| {
+                matches!(sym.st_info, SymType::Func | SymType::Object)
+                    && sym.is_defined()
+            })
+            .filter(|sym| {
+                if sym.st_size == 0 {
+                    sym.st_value == vaddr
+                } else {
+                    sym.st_value <= vaddr
+                        && vaddr - sym.st_value < sym.st_size
+                }
+            })
+            .fold(None, |best: Option<SymTabEnt>, sym| match best {
+                Some(best) if best.st_value >= sym.st_value => Some(best),
+                _ => Some(sym),
+            })
+    }
+
+    /// Returns an iterator over the GNU indirect function symbols of the
+    /// `ShtSymTab` section and then the `ShtDynSym` section
+    pub fn ifunc_symbols(&'a self) -> impl Iterator<Item = SymTabEnt> + 'a {
+        [SectionType::ShtSymTab, SectionType::ShtDynSym]
+            .into_iter()
+            .filter_map(|ty| self.find_section_by_type(ty))
+            .flat_map(|sh| self.symtab_iter(sh))
+            .filter(|sym| sym.is_ifunc())
+    }
+
+    /// Returns an iterator over the symbols of the `ShtSymTab` section and
+    /// then the `ShtDynSym` section
+    /// Section and file symbols of the dynamic symbol table are skipped as
+    /// well as the dynamic symbols already found in the static one
+    /// Without the `alloc` feature the static symbol table is scanned for
+    /// every dynamic symbol which is quadratic in the number of symbols
+    pub fn all_symbols(&'a self) -> impl Iterator<Item = SymTabEnt> + 'a {
+        let symtab = self.find_section_by_type(SectionType::ShtSymTab);
+        let dynsym = self.find_section_by_type(SectionType::ShtDynSym);
+
+        let statics = symtab.into_iter().flat_map(|sh| self.symtab_iter(sh));
+        let dynamics = dynsym.into_iter().flat_map(move |sh| {
+            let strtab = self.linked_section(&sh).ok();
+            let has_symbol = self.symbol_filter(symtab);
+            self.symtab_iter(sh)
+                .filter(|sym| {
+                    !matches!(sym.st_info, SymType::Section | SymType::File)
+                })
+                .filter(move |sym| {
+                    let name = strtab.and_then(|st| self.sym_name(*sym, &st));
+                    !has_symbol(sym, name)
+                })
+        });
+
+        statics.chain(dynamics)
+    }
+
+    /// Returns an iterator over the defined global and weak symbols
+    /// The `ShtSymTab` section is used for relocatable files and the
+    /// `ShtDynSym` section otherwise
+    pub fn exported_symbols(
+        &'a self,
+    ) -> impl Iterator<Item = SymTabEnt> + 'a {
+        self.interface_symbols().filter(|sym| sym.is_defined())
+    }
+
+    /// Returns an iterator over the undefined global and weak symbols
+    /// The `ShtSymTab` section is used for relocatable files and the
+    /// `ShtDynSym` section otherwise
+    pub fn imported_symbols(
+        &'a self,
+    ) -> impl Iterator<Item = SymTabEnt> + 'a {
+        self.interface_symbols().filter(|sym| !sym.is_defined())
+    }
+
+    /// Returns the global and weak symbols of the symbol table which
+    /// holds the interface of the file
+    fn interface_symbols(&'a self) -> impl Iterator<Item = SymTabEnt> + 'a {
+        let ty = if self.is_relocatable() {
+            SectionType::ShtSymTab
+        } else {
+            SectionType::ShtDynSym
+        };
+        self.find_section_by_type(ty)
+            .into_iter()
+            .flat_map(|sh| self.symtab_iter(sh))
+            .filter(|sym| sym.is_global() || sym.is_weak())
+    }
+
+    /// Returns a predicate telling if the symbol table `symtab` has a
+    /// symbol with the same value, size and name as a symbol
+    /// The keys of the symbols are collected once to be looked up quickly
+    #[cfg(feature = "alloc")]
+    fn symbol_filter(
+        &'a self,
+        symtab: Option<SectionHeader>,
+    ) -> impl Fn(&SymTabEnt, Option<&'a str>) -> bool + 'a {
+        let keys: alloc::collections::BTreeSet<_> = symtab
+            .into_iter()
+            .flat_map(|sh| {
+                let strtab = self.linked_section(&sh).ok();
+                self.symtab_iter(sh).map(move |sym| {
+                    let name = strtab.and_then(|st| self.sym_name(sym, &st));
+                    (sym.st_value, sym.st_size, name)
+                })
+            })
+            .collect();
+        move |sym, name| keys.contains(&(sym.st_value, sym.st_size, name))
+    }
+
+    /// Returns a predicate telling if the symbol table `symtab` has a
+    /// symbol with the same value, size and name as a symbol
+    /// Without an allocator the whole table is scanned for every symbol
+    #[cfg(not(feature = "alloc"))]
+    fn symbol_filter(
+        &'a self,
+        symtab: Option<SectionHeader>,
+    ) -> impl Fn(&SymTabEnt, Option<&'a str>) -> bool + 'a {
+        let strtab = symtab.and_then(|sh| self.linked_section(&sh).ok());
+        move |sym, name| {
+            let symbols = symtab.map(|sh| self.symtab_iter(sh));
+            symbols.into_iter().flatten().any(|other| {
+                other.st_value == sym.st_value
+                    && other.st_size == sym.st_size
+                    && strtab.and_then(|st| self.sym_name(other, &st)) == name
+            })
+        }
+    }
+
+    /// Returns an iterator over the symbols named `name` of the symbol
+    /// table `sh` using the string table found in its `sh_link`
+    fn symbols_named<'b>(
+        &'a self,
+        sh: SectionHeader,
+        name: &'b str,
+    ) -> impl Iterator<Item = SymTabEnt> + 'b
+    where
+        'a: 'b,
+    {
+        let strtab = self.section_at_index(sh.sh_link as usize).ok();
+        strtab.into_iter().flat_map(move |strtab| {
+            self.symtab_iter(sh)
+                .filter(move |&sym| self.sym_name(sym, &strtab) == Some(name))
+        })
+    }
+
+    /// Returns the slice for the specified section
+    pub fn get_section(
+        &'a self,
+        sh: SectionHeader,
+    ) -> Result<&'a [u8]> {
+        let end = sh
+            .sh_offset
+            .checked_add(sh.sh_size)
+            .ok_or(Error::Overflow)?;
+
+        // Offsets beyond the host address space can not be in the file
+        let start = usize::try_from(sh.sh_offset)
+            .map_err(|_err| Error::UnreadableSection)?;
+        let end =
+            usize::try_from(end).map_err(|_err| Error::UnreadableSection)?;
+
+        self.elf.get(start..end).ok_or(Error::UnreadableSection)
+    }
+
+    /// Returns the slice for the file image of the specified segment
+    /// Only `p_filesz` bytes are in the file even if `p_memsz` is larger
+    pub fn get_segment_data(
+        &'a self,
+        ph: &ProgramHeader,
+    ) -> Result<&'a [u8]> {
+        let end = ph
+            .p_offset
+            .checked_add(ph.p_filesz)
+            .ok_or(Error::Overflow)?;
+
+        // Offsets beyond the host address space can not be in the file
+        let start =
+            usize::try_from(ph.p_offset).map_err(|_err| Error::TruncatedElf)?;
+        let end = usize::try_from(end).map_err(|_err| Error::TruncatedElf)?;
+
+        self.elf.get(start..end).ok_or(Error::TruncatedElf)
+    }
+
+    /// Returns the compression header of a `SHF_COMPRESSED` section
+    /// The data following the header is left to the caller to decompress
+    #[must_use]
+    pub fn compression_header(
+        &'a self,
+        sh: &SectionHeader,
+    ) -> Option<CompressedSectionHeader> {
+        if !sh.sh_flags.is_compressed() {
+            return None;
+        }
+
+        CompressedSectionHeader::new()
+            .parse(
+                self.get_section(*sh).ok()?,
+                self.file_header.e_class,
+                self.file_header.e_data,
+            )
+            .ok()
+    }
+
+    /// This function returns the section name from the shstrtab
+    /// The shstrtab is located once by `parse` and cached in `shtstrtab`
+    #[must_use]
+    pub fn section_name(&'a self, sh: SectionHeader) -> Option<&'a str> {
+        core::str::from_utf8(self.section_name_bytes(sh)?).ok()
+    }
+
+    /// Returns the raw section name bytes from the shstrtab without
+    /// checking that they are valid UTF-8
+    #[must_use]
+    pub fn section_name_bytes(
+        &'a self,
+        sh: SectionHeader,
+    ) -> Option<&'a [u8]> {
+        self.ndx_name_bytes(sh.sh_name as usize, &self.shtstrtab?)
+    }
+
+    /// Returns the null terminated string at `ndx` in the `strtab` section
+    #[must_use]
+    pub fn ndx_name(
+        &'a self,
+        ndx: usize,
+        strtab: &SectionHeader,
+    ) -> Option<&'a str> {
+        core::str::from_utf8(self.ndx_name_bytes(ndx, strtab)?).ok()
+    }
+
+    /// Returns the raw bytes of the null terminated string at `ndx` in the
+    /// `strtab` section without checking that they are valid UTF-8
+    #[must_use]
+    pub fn ndx_name_bytes(
+        &'a self,
+        ndx: usize,
+        strtab: &SectionHeader,
+    ) -> Option<&'a [u8]> {
+        bytes_at(self.get_section(*strtab).ok()?, ndx as u64)
+    }
+
+    /// Returns the `StrTabIterator` over the offsets and the strings of the
+    /// `strtab` section
+    pub fn strtab_iter(
+        &'a self,
+        strtab: &SectionHeader,
+    ) -> Result<StrTabIterator<'a>> {
+        Ok(StrTabIterator::new(self.get_section(*strtab)?))
+    }
+
+    /// Returns the symbol name from the string table linked to its symbol
+    /// table which is found in the `sh_link` of the symbol table section
+    #[must_use]
+    pub fn sym_name(
+        &'a self,
+        sym: SymTabEnt,
+        strtab: &SectionHeader,
+    ) -> Option<&'a str> {
+        self.ndx_name(sym.st_name as usize, strtab)
+    }
+
+    /// Returns the section index of the symbol at `sym_ndx` in the symbol
+    /// table `symtab_sh`
+    /// When `st_shndx` is `SHN_XINDEX` the real index is read from the
+    /// `ShtSymTabShndx` section whose `sh_link` points to the symbol table
+    pub fn true_section_index(
+        &'a self,
+        sym_ndx: usize,
+        symtab_sh: &SectionHeader,
+    ) -> Result<u32> {
+        let sym = self
+            .symtab_iter(*symtab_sh)
+            .nth(sym_ndx)
+            .ok_or(Error::InvalidSectionIndex)?;
+        if sym.st_shndx != SHN_XINDEX {
+            return Ok(sym.st_shndx as u32);
+        }
+
+        // Get the extended section index table of the symbol table
+        let shndx = self
+            .iter_sections_of_type(SectionType::ShtSymTabShndx)
+            .find(|sh| sh.sh_link as usize == symtab_sh.sh_ndx)
+            .ok_or(Error::BadElf)?;
+
+        // Every symbol has a 4 byte entry at the same position
+        let start = sym_ndx.checked_mul(0x04).ok_or(Error::Overflow)?;
+        let end = start.checked_add(0x04).ok_or(Error::Overflow)?;
+        let data = self.get_section(shndx)?;
+        u32::endian_parse(start..end, data, &self.file_header.e_data)
+            .map_err(|_err| Error::TruncatedElf)
+    }
+
+    /// Parse the section header at `ndx` directly from the section header
+    /// table without walking the previous entries
+    /// This is useful to follow the `sh_link` and `sh_info` references
+    pub fn section_at_index(&self, ndx: usize) -> Result<SectionHeader> {
+        // The index must be inside the section header table
+        if ndx >= self.file_header.e_shnum {
+            return Err(Error::InvalidSectionIndex);
+        }
+
+        let entsize = self.file_header.e_shentsize as usize;
+        let start = ndx
+            .checked_mul(entsize)
+            .and_then(|offset| {
+                let shoff = usize::try_from(self.file_header.e_shoff).ok()?;
+                offset.checked_add(shoff)
+            })
+            .ok_or(Error::Overflow)?;
+        let end = start.checked_add(entsize).ok_or(Error::Overflow)?;
+
+        let mut sh = SectionHeader::new().parse(
+            self.elf.get(start..end).ok_or(Error::TruncatedElf)?,
+            self.file_header.e_class,
+            self.file_header.e_data,
+        )?;
+        sh.sh_ndx = ndx;
+
+        Ok(sh)
+    }
+
+    /// Returns the program header at index `ndx` of the program header
+    /// table without iterating over the previous ones
+    pub fn program_header_at_index(
+        &self,
+        ndx: usize,
+    ) -> Result<ProgramHeader> {
+        // The index must be inside the program header table
+        if ndx >= self.file_header.e_phnum {
+            return Err(Error::InvalidProgramHeaderIndex);
+        }
+
+        let entsize = self.file_header.e_phentsize as usize;
+        let start = ndx
+            .checked_mul(entsize)
+            .and_then(|offset| {
+                let phoff = usize::try_from(self.file_header.e_phoff).ok()?;
+                offset.checked_add(phoff)
+            })
+            .ok_or(Error::Overflow)?;
+        let end = start.checked_add(entsize).ok_or(Error::Overflow)?;
+
+        ProgramHeader::new().parse(
+            self.elf.get(start..end).ok_or(Error::TruncatedElf)?,
+            self.file_header.e_class,
+            self.file_header.e_data,
+        )
+    }
+
+    /// Returns the section referenced by the `sh_link` of `sh` like the
+    /// string table of a symbol table
+    pub fn linked_section(&self, sh: &SectionHeader) -> Result<SectionHeader> {
+        // A zero link means there is no linked section
+        if sh.sh_link == SHN_UNDEF as u32 {
+            return Err(Error::SectionNotFound);
+        }
+        self.section_at_index(sh.sh_link as usize)
+            .map_err(|_err| Error::SectionNotFound)
+    }
+
+    /// Returns the section referenced by the `sh_info` of `sh` like the
+    /// section a relocation section applies to
+    /// The `sh_info` is only a section index when `SHF_INFO_LINK` is set
+    pub fn info_section(&self, sh: &SectionHeader) -> Result<SectionHeader> {
+        if !sh.sh_flags.is_info_link() || sh.sh_info == SHN_UNDEF as u32 {
+            return Err(Error::SectionNotFound);
+        }
+        self.section_at_index(sh.sh_info as usize)
+            .map_err(|_err| Error::SectionNotFound)
+    }
+
+    /// Returns the section header string table pointed by `e_shstrndx`
+    fn find_shstrtab(&self) -> Option<SectionHeader> {
+        let ndx = match self.file_header.e_shstrndx {
+            // The elf file has no section name string table
+            SHN_UNDEF => return None,
+            // The real index is stored in the `sh_link` of the first section
+            SHN_XINDEX => self.section_at_index(0).ok()?.sh_link as usize,
+            ndx => ndx as usize,
+        };
+
+        let sh = self.section_at_index(ndx).ok()?;
+        if sh.sh_type == SectionType::ShtStrTab {
+            Some(sh)
+        } else {
+            None
+        }
+    }
+
+    /// Perform sanity checks on the parsed elf file
+    /// The header sizes, the header tables, the section string table index,
+    /// the section links and the file range and alignment of every section
+    /// and segment are checked
+    pub fn validate(&self) -> Result<()> {
+        let header = &self.file_header;
+
+        // The header sizes are fixed for each elf class
+        let (ehsize, phentsize, shentsize) = match header.e_class {
+            ElfClass::Class32 => (0x34, 0x20, 0x28),
+            ElfClass::Class64 => (0x40, 0x38, 0x40),
+            ElfClass::None => return Err(Error::UnsupportedClass),
+        };
+        if header.e_ehsize != ehsize
+            || (header.e_phnum != 0 && header.e_phentsize != phentsize)
+            || (header.e_shnum != 0 && header.e_shentsize != shentsize)
+        {
+            return Err(Error::BadElf);
+        }
+
+        // The program and section header tables must be inside the file
+        let tables = [
+            (header.e_phoff, header.e_phnum, header.e_phentsize),
+            (header.e_shoff, header.e_shnum, header.e_shentsize),
+        ];
+        for (offset, num, entsize) in tables {
+            let end = num
+                .checked_mul(entsize as usize)
+                .and_then(|size| offset.checked_add(size as u64))
+                .ok_or(Error::Overflow)?;
+            if num != 0 && end > self.elf.len() as u64 {
+                return Err(Error::TruncatedElf);
+            }
+        }
+
+        // The section string table index must point to a section
+        let shstrndx = header.e_shstrndx;
+        if shstrndx != SHN_UNDEF
+            && shstrndx != SHN_XINDEX
+            && shstrndx as usize >= header.e_shnum
+        {
+            return Err(Error::InvalidSectionIndex);
+        }
+
+        for sh in self.section_iter() {
+            // Sections without data in the file have no range to check
+            if sh.sh_type != SectionType::ShtNoBits
+                && sh.sh_type != SectionType::ShtNull
+            {
+                self.get_section(sh)?;
+            }
+
+            // The alignment must be zero or a power of two
+            if sh.sh_addralign != 0 && !sh.sh_addralign.is_power_of_two() {
+                return Err(Error::InvalidAlignment);
+            }
+
+            // The linked sections must exist
+            let info_link = sh.sh_flags.is_info_link();
+            if sh.sh_link as usize >= header.e_shnum
+                || (info_link && sh.sh_info as usize >= header.e_shnum)
+            {
+                return Err(Error::InvalidSectionIndex);
+            }
+        }
+
+        for ph in self.program_iter() {
+            // Unused entries have no range to check
+            if ph.p_type != ProgramType::PtNull {
+                let end = ph
+                    .p_offset
+                    .checked_add(ph.p_filesz)
+                    .ok_or(Error::Overflow)?;
+                if end > self.elf.len() as u64 {
+                    return Err(Error::TruncatedElf);
+                }
+            }
+
+            // The alignment must be zero or a power of two
+            if ph.p_align != 0 && !ph.p_align.is_power_of_two() {
+                return Err(Error::InvalidAlignment);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the elf file and populate the struct
+    pub fn parse(mut self) -> Result<Self> {
+        // Parse the elf header
+        self.file_header = self
+            .file_header
+            .parse_with_options(self.elf, &self.options)?;
+
+        // Find the section header string table once
+        if self.shtstrtab.is_none() {
+            self.shtstrtab = self.find_shstrtab();
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::println;
+
+    #[test]
+    fn parse_elf32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice());
+        let e = e.parse().unwrap();
+        for program in e.program_iter() {
+            println!("{:#x?}", program);
+        }
+        for section in e.section_iter() {
+            println!("{:#x?}", section);
+        }
+        println!("{:#x?}", e);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn parse_elf64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice());
+        let e = e.parse().unwrap();
+        for program in e.program_iter() {
+            println!("{:#x?}", program);
+        }
+        for section in e.section_iter() {
+            println!("{:#x?}", section);
+        }
+        println!("{:#x?}", e);
+    }
+
+    #[test]
+    fn parse_section_type_msb() {
+        // Hand craft a big endian 32-bit symbol table section header
+        let mut sh = [0u8; 0x28];
+        sh[0x04..0x08].copy_from_slice(&2u32.to_be_bytes());
+        let sh = SectionHeader::new()
+            .parse(&sh, ElfClass::Class32, ElfData::ElfData2Msb)
+            .unwrap();
+        assert_eq!(sh.sh_type, SectionType::ShtSymTab);
+    }
+
+    #[test]
+    fn parse_program_type_msb() {
+        // Hand craft a big endian 32-bit GNU stack program header
+        let mut ph = [0u8; 0x20];
+        ph[0x00..0x04].copy_from_slice(&0x6474e551u32.to_be_bytes());
+        let ph = ProgramHeader::new()
+            .parse(&ph, ElfClass::Class32, ElfData::ElfData2Msb)
+            .unwrap();
+        assert_eq!(ph.p_type, ProgramType::PtGnuStack);
+    }
+
+    #[test]
+    fn parse_program_type_tls() {
+        let mut ph = [0u8; 0x20];
+        ph[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+        let ph = ProgramHeader::new()
+            .parse(&ph, ElfClass::Class32, ElfData::ElfData2Lsb)
+            .unwrap();
+        assert_eq!(ph.p_type, ProgramType::PtTls);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn parse_symtab64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let strtab = e.section_at_index(symtab.sh_link as usize).unwrap();
+        // main is a global function so its st_info holds a binding as well
+        let main = e
+            .symtab_iter(symtab)
+            .find(|sym| e.sym_name(*sym, &strtab) == Some("main"))
+            .unwrap();
+        assert_eq!(main.st_info, SymType::Func);
+        assert_eq!(main.st_bind, SymBinding::Global);
+        assert_eq!(main.st_value, 0x1119);
+        assert_eq!(e.symtab_iter(symtab).count(), 36);
+        assert_eq!(e.symtab_iter(symtab).nth(31), Some(main));
+    }
+
+    #[test]
+    fn find_shstrtab32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.shtstrtab.map(|sh| sh.sh_ndx), Some(5));
+        let text = e.section_iter().nth(1).unwrap();
+        assert_eq!(e.section_name(text), Some(".text"));
+    }
+
+    #[test]
+    fn find_shstrtab_xindex32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        // Move the shstrtab index into the sh_link of the first section
+        let shoff = Elf::new(file.as_slice())
+            .parse()
+            .unwrap()
+            .file_header
+            .e_shoff as usize;
+        file[0x32..0x34].copy_from_slice(&SHN_XINDEX.to_le_bytes());
+        file[shoff + 0x18..shoff + 0x1c].copy_from_slice(&5u32.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.shtstrtab.map(|sh| sh.sh_ndx), Some(5));
+    }
+
+    #[test]
+    fn extended_shnum32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        // Move the section count into the sh_size of the first section
+        let shoff = Elf::new(file.as_slice())
+            .parse()
+            .unwrap()
+            .file_header
+            .e_shoff as usize;
+        file[0x30..0x32].copy_from_slice(&0u16.to_le_bytes());
+        file[shoff + 0x14..shoff + 0x18].copy_from_slice(&6u32.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.file_header.e_shnum, 6);
+        assert_eq!(e.section_iter().count(), 6);
+
+        // A count past the end of the file is refused
+        file[shoff + 0x14..shoff + 0x18]
+            .copy_from_slice(&0x0100_0000u32.to_le_bytes());
+        assert!(matches!(
+            Elf::new(file.as_slice()).parse(),
+            Err(Error::TruncatedElf)
+        ));
+        file[shoff + 0x14..shoff + 0x18]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Elf::new(file.as_slice()).parse(),
+            Err(Error::TruncatedElf)
+        ));
+    }
+
+    #[test]
+    fn extended_phnum32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        // Move the program header count into the sh_info of section zero
+        let shoff = Elf::new(file.as_slice())
+            .parse()
+            .unwrap()
+            .file_header
+            .e_shoff as usize;
+        file[0x2c..0x2e].copy_from_slice(&program::PN_XNUM.to_le_bytes());
+        file[shoff + 0x1c..shoff + 0x20].copy_from_slice(&3u32.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.file_header.e_phnum, 3);
+        assert_eq!(e.program_iter().count(), 3);
+    }
+
+    #[test]
+    fn get_section_overflow() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let mut sh = e.section_iter().nth(1).unwrap();
+        sh.sh_offset = u64::MAX;
+        assert!(matches!(e.get_section(sh), Err(Error::Overflow)));
+        assert_eq!(e.section_name(sh), Some(".text"));
+    }
+
+    #[test]
+    fn section_at_index32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        for section in e.section_iter() {
+            assert_eq!(e.section_at_index(section.sh_ndx).unwrap(), section);
+        }
+        assert!(matches!(
+            e.section_at_index(6),
+            Err(Error::InvalidSectionIndex)
+        ));
+        assert!(matches!(
+            e.section_at_index(usize::MAX),
+            Err(Error::InvalidSectionIndex)
+        ));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn find_section64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.find_section(".dynamic").map(|sh| sh.sh_ndx), Some(19));
+        assert_eq!(
+            e.find_section_by_type(SectionType::ShtDynamic),
+            e.find_section(".dynamic")
+        );
+        assert_eq!(e.iter_sections_of_type(SectionType::ShtNotes).count(), 3);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn rela_iter64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let rela = e.find_section(".rela.dyn").unwrap();
+        let mut it = e.rela_iter(rela);
+        let first = it.next().unwrap();
+        assert_eq!(first.r_offset, 0x3e28);
+        assert_eq!(first.r_addend, 0x1110);
+        // R_X86_64_GLOB_DAT against the first dynamic symbol
+        let glob_dat = it.nth(2).unwrap();
+        assert_eq!((glob_dat.r_sym(), glob_dat.r_type()), (1, 6));
+        assert_eq!(it.count(), 4);
+    }
+
+    #[test]
+    fn parse_rel32() {
+        // Hand craft a 32-bit R_386_JMP_SLOT relocation against symbol 3
+        let mut rel = [0u8; 0x08];
+        rel[0x00..0x04].copy_from_slice(&0x804a00cu32.to_le_bytes());
+        rel[0x04..0x08].copy_from_slice(&0x0307u32.to_le_bytes());
+        let rel = relocation::RelEnt::new()
+            .parse(&rel, ElfClass::Class32, ElfData::ElfData2Lsb)
+            .unwrap();
+        assert_eq!(rel.r_offset, 0x804a00c);
+        assert_eq!((rel.r_sym(), rel.r_type()), (3, 7));
+    }
+
+    #[test]
+    fn decode_relr64() {
+        // An address followed by a bitmap marking the first and third word
+        let mut relr = [0u8; 0x10];
+        relr[0x00..0x08].copy_from_slice(&0x1000u64.to_le_bytes());
+        relr[0x08..0x10].copy_from_slice(&0b1011u64.to_le_bytes());
+        let sh = SectionHeader {
+            sh_size: relr.len() as u64,
+            sh_entsize: 8,
+            ..SectionHeader::new()
+        };
+        let mut it = relocation::RelrIterator::new(
+            sh,
+            ElfClass::Class64,
+            ElfData::ElfData2Lsb,
+            &relr,
+        );
+        assert_eq!(it.next(), Some(0x1000));
+        assert_eq!(it.next(), Some(0x1008));
+        assert_eq!(it.next(), Some(0x1018));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn dyn_iter64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let dynamic = e.find_section(".dynamic").unwrap();
+        let mut it = e.dyn_iter(dynamic);
+        let needed = it.next().unwrap();
+        assert_eq!(needed.d_tag, dynamic::DynTag::Needed);
+        // The iterator stops before the terminating null entry
+        assert_eq!(it.count(), 20);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn note_iter64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let abi_tag = e.find_section(".note.ABI-tag").unwrap();
+        let note = e.note_iter(&abi_tag).next().unwrap();
+        assert_eq!((note.n_type, note.name), (1, &b"GNU\0"[..]));
+        assert_eq!(note.desc.len(), 16);
+        // The second note segment holds both the build-id and the ABI tag
+        let types: std::vec::Vec<u32> = e
+            .program_iter()
+            .of_type(ProgramType::PtNote)
+            .flat_map(|ph| e.note_iter_from_segment(&ph))
+            .map(|note| note.n_type)
+            .collect();
+        assert_eq!(types, [5, 3, 1]);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn gnu_build_id64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let build_id = e.gnu_build_id().unwrap();
+        assert_eq!(build_id.len(), 20);
+        assert_eq!(build_id[..4], [0xd8, 0xc1, 0x1a, 0xdc]);
+        // The 32-bit test file was linked without a build-id
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.gnu_build_id(), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn gnu_hash_lookup64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let table = e.gnu_hash_table().unwrap();
+
+        // A shift wider than the hash is rejected instead of overflowing
+        let mut bad = file.clone();
+        let gnu_hash = e.find_section_by_type(SectionType::ShtGnuHash);
+        let offset = gnu_hash.unwrap().sh_offset as usize;
+        bad[offset + 0x0c..offset + 0x10]
+            .copy_from_slice(&40u32.to_le_bytes());
+        let bad = Elf::new(bad.as_slice()).parse().unwrap();
+        assert!(bad.gnu_hash_table().is_none());
+
+        let dynsym = e.find_section_by_type(SectionType::ShtDynSym).unwrap();
+        let dynstr = e.section_at_index(dynsym.sh_link as usize).unwrap();
+        // The executable does not export any symbols through its hash table
+        assert_eq!((table.nbuckets, table.symoffset), (1, 1));
+        let symbols = e.symtab_iter(dynsym);
+        assert_eq!(table.lookup("main", symbols, &dynstr, &e), None);
+        assert_eq!(hash::gnu_hash(b"printf"), 0x156b2bb8);
+    }
+
+    #[test]
+    fn sysv_hash_lookup() {
+        // The static executable was linked without a SysV hash table
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.sysv_hash_table().is_none());
+        assert_eq!(hash::elf_hash(b"printf"), 0x077905a6);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn sysv_hash_lookup64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let table = e.sysv_hash_table().unwrap();
+        assert_eq!(table.nchain, 15);
+        let dynsym = e.find_section_by_type(SectionType::ShtDynSym).unwrap();
+        let dynstr = e.section_at_index(dynsym.sh_link as usize).unwrap();
+        let symbols = e.symtab_iter(dynsym);
+        let sym = table.lookup("exported_func", symbols, &dynstr, &e);
+        assert_eq!(sym.unwrap().st_value, 0x111f);
+        assert_eq!(sym.unwrap().st_size, 27);
+        assert_eq!(table.lookup("local_helper", symbols, &dynstr, &e), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn find_symbol64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let main = e.find_symbol("main").unwrap();
+        assert_eq!((main.st_value, main.st_size), (0x1119, 11));
+        assert_eq!(e.find_symbol("no_such_symbol"), None);
+
+        // Exported symbols are in both the static and dynamic symbol tables
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.find_all_symbols("exported_func").count(), 2);
+        let puts = e.find_all_symbols("puts").last().unwrap();
+        assert_eq!(puts.section_index(), SectionIndex::Undef);
+        // Local symbols are only in the static symbol table
+        assert_eq!(e.find_all_symbols("frame_dummy").count(), 1);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn find_symbol_by_address64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let main = e.find_symbol("main").unwrap();
+        // main is at 0x1119 with a size of 11 bytes
+        assert_eq!(e.find_symbol_by_address(0x1119), Some(main));
+        assert_eq!(e.find_symbol_by_address(0x1123), Some(main));
+        assert_ne!(e.find_symbol_by_address(0x1124), Some(main));
+        assert_eq!(e.find_symbol_by_address(0), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn sym_visibility64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let sym = e.find_symbol("protected_func").unwrap();
+        assert_eq!(sym.visibility(), SymVisibility::Protected);
+        let sym = e.find_symbol("exported_func").unwrap();
+        assert_eq!(sym.visibility(), SymVisibility::default());
+        assert_eq!(SymVisibility::from(0xfe), SymVisibility::Hidden);
+        assert_eq!(SymBinding::default(), SymBinding::None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn sym_helpers64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let sym = e.find_symbol("weak_func").unwrap();
+        assert!(sym.is_defined() && sym.is_weak() && sym.is_function());
+        assert!(!sym.is_global() && !sym.is_local());
+        let sym = e.find_symbol("exported_data").unwrap();
+        assert!(sym.is_global() && sym.is_object());
+        let sym = e.find_symbol("tls_counter").unwrap();
+        assert!(sym.is_tls());
+        let sym = e.find_symbol("puts").unwrap();
+        assert!(!sym.is_defined());
+        let sym = e.find_symbol("frame_dummy").unwrap();
+        assert!(sym.is_local());
+        let sym = e.find_symbol("VERS_1.0").unwrap();
+        assert_eq!(sym.section_index(), SectionIndex::Abs);
+        assert!(sym.is_defined());
+        let sym = e.find_symbol("exported_func").unwrap();
+        assert_eq!(sym.section_index(), SectionIndex::Index(14));
+        assert_eq!(SectionIndex::from(0xfff2), SectionIndex::Common);
+        assert_eq!(SectionIndex::from(0xffff), SectionIndex::Xindex);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn exact_size_iter64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let mut sections = e.section_iter();
+        assert_eq!(sections.len(), 35);
+        sections.next();
+        assert_eq!(sections.size_hint(), (34, Some(34)));
+        assert_eq!(e.program_iter().len(), 13);
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let mut symbols = e.symtab_iter(symtab);
+        assert_eq!(symbols.len(), 36);
+        symbols.nth(30);
+        assert_eq!(symbols.len(), 5);
+
+        // A truncated file only counts the entries it still holds
+        let e = Elf::new(&file[..file.len() - 0x40]).parse().unwrap();
+        let mut sections = e.section_iter();
+        assert_eq!(sections.len(), 34);
+        assert_eq!(sections.by_ref().count(), 34);
+        assert_eq!(sections.len(), 0);
+
+        // A huge table size is bounded by the file size
+        let mut huge = symtab;
+        huge.sh_size = u64::MAX;
+        let room = e.elf.len() - symtab.sh_offset as usize;
+        let bound = room / symtab.sh_entsize as usize;
+        assert_eq!(e.symtab_iter(huge).len(), bound);
+        let all: std::vec::Vec<_> = e.symtab_iter(huge).collect();
+        assert_eq!(all.len(), bound);
+
+        // Entries too small to parse are not counted at all
+        huge.sh_entsize = 0x04;
+        assert_eq!(e.symtab_iter(huge).len(), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn double_ended_iter64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let mut sections = e.section_iter();
+        let last = sections.next_back().unwrap();
+        assert_eq!(last.sh_ndx, 34);
+        assert_eq!(last.sh_type, SectionType::ShtStrTab);
+        assert_eq!(sections.next().unwrap().sh_ndx, 0);
+        assert_eq!(sections.len(), 33);
+        let symtab = sections.rfind(|sh| sh.sh_type == SectionType::ShtSymTab);
+        assert_eq!(symtab.unwrap().sh_ndx, 32);
+
+        let forward: std::vec::Vec<_> = e.section_iter().collect();
+        let mut backward: std::vec::Vec<_> = e.section_iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let last = e.program_iter().next_back().unwrap();
+        assert_eq!(last.p_type, ProgramType::PtGnuRelro);
+        assert_eq!(e.program_iter().rev().count(), 13);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn iter_of_type64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let notes = e.section_iter().of_type(SectionType::ShtNotes);
+        assert_eq!(notes.count(), 3);
+        let loads = e.program_iter().of_type(ProgramType::PtLoad);
+        assert_eq!(loads.count(), 4);
+
+        let mask = SectionFlags::ALLOC | SectionFlags::EXECINSTR;
+        let mut exec = e.section_iter().of_flags(mask);
+        assert!(exec.all(|sh| sh.sh_flags.is_exec()));
+        let text = e.find_section(".text").unwrap();
+        assert!(e.section_iter().of_flags(mask).any(|sh| sh == text));
+    }
+
+    #[test]
+    fn parse_machine32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let machines = [
+            (0xb7u16, file::ElfMachine::Aarch64),
+            (0x08, file::ElfMachine::Mips),
+            (0x15, file::ElfMachine::PowerPc64),
+            (0x102, file::ElfMachine::LoongArch),
+            (0x1234, file::ElfMachine::UnDefined(0x1234)),
+        ];
+        for (value, machine) in machines {
+            file[0x12..0x14].copy_from_slice(&value.to_le_bytes());
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert_eq!(e.file_header.e_machine, machine);
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn display_enums64() {
+        use std::string::ToString;
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let header = &e.file_header;
+        assert_eq!(header.e_class.to_string(), "ELF64");
+        assert_eq!(
+            header.e_data.to_string(),
+            "2's complement, little endian"
+        );
+        assert_eq!(header.e_abi.to_string(), "UNIX - System V");
+        assert_eq!(header.e_type.to_string(), "DYN");
+        assert_eq!(header.e_machine.to_string(), "x86-64");
+        let rela = e.find_section(".rela.dyn").unwrap();
+        assert_eq!(rela.sh_type.to_string(), "RELA");
+        let load = e.program_iter().of_type(ProgramType::PtLoad).next();
+        assert_eq!(load.unwrap().p_type.to_string(), "LOAD");
+    }
+
+    #[test]
+    fn display_error() {
+        use std::string::ToString;
+        assert_eq!(Error::BadElf.to_string(), "not a valid ELF file");
+        let err: &dyn core::error::Error = &Error::UnreadableSection;
+        assert_eq!(err.to_string(), "section data is outside the file");
+    }
+
+    #[test]
+    fn parse_compression_header() {
+        // Hand craft a big endian 64-bit zstd compression header
+        let mut ch = [0u8; 0x18];
+        ch[0x00..0x04].copy_from_slice(&2u32.to_be_bytes());
+        ch[0x08..0x10].copy_from_slice(&0x1234u64.to_be_bytes());
+        ch[0x10..0x18].copy_from_slice(&8u64.to_be_bytes());
+        let ch = CompressedSectionHeader::new()
+            .parse(&ch, ElfClass::Class64, ElfData::ElfData2Msb)
+            .unwrap();
+        assert_eq!(ch.ch_type, section::ELFCOMPRESS_ZSTD);
+        assert_eq!((ch.ch_size, ch.ch_addralign), (0x1234, 8));
+
+        // Uncompressed sections have no compression header
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let text = e.find_section(".text").unwrap();
+        assert_eq!(e.compression_header(&text), None);
+    }
+
+    #[test]
+    fn validate32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.validate().is_ok());
+
+        // A segment past the end of the file is rejected
+        let mut bad = file.clone();
+        let p_filesz_at = e.file_header.e_phoff as usize + 0x10;
+        bad[p_filesz_at..p_filesz_at + 4]
+            .copy_from_slice(&0x0100_0000u32.to_le_bytes());
+        let e = Elf::new(bad.as_slice()).parse().unwrap();
+        assert!(matches!(e.validate(), Err(Error::TruncatedElf)));
+
+        // A truncated section header table is rejected
+        let e = Elf::new(&file[..file.len() - 1]).parse().unwrap();
+        assert!(matches!(e.validate(), Err(Error::TruncatedElf)));
+
+        // A section alignment which is not a power of two is rejected
+        // The `sh_addralign` of the section at index 1 is patched
+        let mut bad = file.clone();
+        let shentsize = e.file_header.e_shentsize as usize;
+        let align = e.file_header.e_shoff as usize + shentsize + 0x20;
+        bad[align..align + 4].copy_from_slice(&3u32.to_le_bytes());
+        let e = Elf::new(bad.as_slice()).parse().unwrap();
+        assert!(matches!(e.validate(), Err(Error::InvalidAlignment)));
+
+        // An out of range section string table index is rejected
+        file[0x32..0x34].copy_from_slice(&6u16.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(matches!(e.validate(), Err(Error::InvalidSectionIndex)));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn validate64() {
+        for path in ["./tests/elf_test64", "./tests/elf_test_so64"] {
+            let file = std::fs::read(path)
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert!(e.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn parse_section_addr64() {
+        // Hand craft a 64-bit section header with a high kernel address
+        let mut sh = [0u8; 0x40];
+        sh[0x10..0x18].copy_from_slice(&0xffffffff80000000u64.to_le_bytes());
+        sh[0x20..0x28].copy_from_slice(&0x1_0000_0000u64.to_le_bytes());
+        let sh = SectionHeader::new()
+            .parse(&sh, ElfClass::Class64, ElfData::ElfData2Lsb)
+            .unwrap();
+        assert_eq!(sh.sh_addr, 0xffffffff80000000);
+        assert_eq!(sh.sh_size, 0x1_0000_0000);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn vaddr_file_offset64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        // .data is mapped at a different address than its file offset
+        let data = e.find_section(".data").unwrap();
+        assert_ne!(data.sh_addr, data.sh_offset);
+        let offset = e.vaddr_to_file_offset(data.sh_addr);
+        assert_eq!(offset, Some(data.sh_offset));
+        let vaddr = e.file_offset_to_vaddr(data.sh_offset);
+        assert_eq!(vaddr, Some(data.sh_addr));
+        // .bss has no file image
+        let bss = e.find_section(".bss").unwrap();
+        assert_eq!(e.vaddr_to_file_offset(bss.sh_addr), None);
+        assert_eq!(e.file_offset_to_vaddr(file.len() as u64), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn get_segment_data64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let interp = e.program_iter().of_type(ProgramType::PtInterp).next();
+        let data = e.get_segment_data(&interp.unwrap()).unwrap();
+        assert_eq!(data, b"/lib64/ld-linux-x86-64.so.2\0");
+
+        let mut ph = interp.unwrap();
+        ph.p_filesz = u64::MAX;
+        assert!(matches!(e.get_segment_data(&ph), Err(Error::Overflow)));
+        ph.p_filesz = file.len() as u64;
+        assert!(matches!(e.get_segment_data(&ph), Err(Error::TruncatedElf)));
+    }
+
+    #[test]
+    fn get_interp() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.get_interp(), None);
+        #[cfg(target_pointer_width = "64")]
+        {
+            let file = std::fs::read("./tests/elf_test64")
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert_eq!(e.get_interp(), Some("/lib64/ld-linux-x86-64.so.2"));
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn needed_libraries64() {
+        let mut file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let needed: std::vec::Vec<_> = e.needed_libraries().collect();
+        assert_eq!(needed, ["libc.so.6", "ld-linux-x86-64.so.2"]);
+
+        // Without section headers the dynamic segment is used
+        file[0x3c..0x3e].copy_from_slice(&0u16.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.section_iter().len(), 0);
+        let needed: std::vec::Vec<_> = e.needed_libraries().collect();
+        assert_eq!(needed, ["libc.so.6", "ld-linux-x86-64.so.2"]);
+
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.needed_libraries().count(), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn dyn_strings64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.soname(), Some("libtest.so.1"));
+        assert_eq!(e.runpath(), Some("/opt/test/lib"));
+        assert_eq!(e.rpath(), None);
+
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.soname(), None);
+    }
+
+    #[test]
+    fn file_kind_predicates() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.is_executable() && e.is_statically_linked());
+        assert!(!e.is_pie() && !e.is_shared_library() && !e.is_relocatable());
+        #[cfg(target_pointer_width = "64")]
+        {
+            let file = std::fs::read("./tests/elf_test64")
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert!(e.is_pie() && e.is_executable());
+            assert!(e.is_dynamically_linked() && !e.is_shared_library());
+
+            let file = std::fs::read("./tests/elf_test_so64")
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert!(e.is_shared_library() && e.is_dynamically_linked());
+            assert!(!e.is_pie() && !e.is_executable());
+        }
+    }
+
+    #[test]
+    fn security_predicates() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        // The static executable has no GNU stack or relro segment
+        assert!(!e.has_nx_stack() && !e.has_relro() && !e.has_full_relro());
+        assert!(!e.is_stripped());
+        #[cfg(target_pointer_width = "64")]
+        {
+            let file = std::fs::read("./tests/elf_test64")
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert!(e.has_nx_stack() && e.has_relro());
+            assert!(!e.has_full_relro());
+
+            let file = std::fs::read("./tests/elf_test_so64")
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert!(e.has_full_relro());
+        }
+    }
+
+    #[test]
+    fn load_addresses32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let min = e.min_load_address().unwrap();
+        let max = e.max_load_address().unwrap();
+        assert_eq!((min, max), (0x08048000, 0x0804a00c));
+        assert_eq!(e.image_size(), Some(0x3000));
+
+        // A truncated file has no readable load segments
+        let e = Elf::new(&file[..0x34]).parse().unwrap();
+        assert_eq!(e.min_load_address(), None);
+        assert_eq!(e.image_size(), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn sections_in_segment64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let data = e.program_iter().of_type(ProgramType::PtLoad).last();
+        let data = data.unwrap();
+        let name = |sh| e.section_name(sh).unwrap();
+        let in_file: std::vec::Vec<_> =
+            e.sections_in_segment(&data).map(name).collect();
+        let in_memory: std::vec::Vec<_> =
+            e.sections_in_segment_vaddr(&data).map(name).collect();
+        assert!(in_file.contains(&".data") && !in_file.contains(&".bss"));
+        assert!(in_memory.contains(&".data") && in_memory.contains(&".bss"));
+
+        let interp = e.program_iter().of_type(ProgramType::PtInterp).next();
+        let mut sections = e.sections_in_segment(&interp.unwrap());
+        assert_eq!(sections.next().map(name), Some(".interp"));
+        assert_eq!(sections.next(), None);
+    }
+
+    #[test]
+    fn hash_headers32() {
+        use std::collections::HashSet;
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let sections: HashSet<_> = e.section_iter().collect();
+        assert_eq!(sections.len(), 6);
+        let segments: HashSet<_> = e.program_iter().collect();
+        assert_eq!(segments.len(), 3);
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let symbols: HashSet<_> = e.symtab_iter(symtab).collect();
+        assert!(symbols.contains(&e.symtab_iter(symtab).next().unwrap()));
+    }
+
+    #[test]
+    fn section_flags_ops() {
+        let flags = SectionFlags::WRITE | SectionFlags::ALLOC;
+        assert!(flags.is_write() && flags.is_alloc() && !flags.is_exec());
+        assert_eq!(flags.bits(), 0b11);
+        assert_eq!(flags & SectionFlags::ALLOC, SectionFlags::ALLOC);
+        assert_eq!(flags ^ SectionFlags::WRITE, SectionFlags::ALLOC);
+        assert_eq!((!flags).bits() & 0b11, 0);
+        assert_eq!(SectionFlags::from_bits(0), SectionFlags::empty());
+        assert!(flags.contains(SectionFlags::empty()));
+    }
+
+    #[test]
+    fn raw_type_values() {
+        let sections = [
+            SectionType::ShtProgBits,
+            SectionType::ShtSymTab,
+            SectionType::ShtDynSym,
+            SectionType::ShtRelr,
+            SectionType::ShtGnuHash,
+            SectionType::ShtOs(0x6ffffff0),
+            SectionType::ShtProc(0x70000001),
+            SectionType::ShtUser(0x80000000),
+            SectionType::Unknown(0x15),
+        ];
+        for ty in sections {
+            assert_eq!(SectionType::from(ty.raw_value()), ty);
+        }
+        let os = SectionType::from(0x60000000);
+        assert_eq!(os, SectionType::ShtOs(0x60000000));
+        assert_eq!(SectionType::from(0x15), SectionType::Unknown(0x15));
+
+        let programs = [
+            ProgramType::PtLoad,
+            ProgramType::PtTls,
+            ProgramType::PtGnuStack,
+            ProgramType::PtGnuProperty,
+            ProgramType::PtOs(0x6fffffff),
+            ProgramType::PtProc(0x70000000),
+            ProgramType::Unknown(0x80000000),
+        ];
+        for ty in programs {
+            assert_eq!(ProgramType::from(ty.raw_value()), ty);
+        }
+        let os = ProgramType::from(0x60000001);
+        assert_eq!(os, ProgramType::PtOs(0x60000001));
+        assert_eq!(ProgramType::from(8), ProgramType::Unknown(8));
+
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        for section in e.section_iter() {
+            let raw = section.sh_type.raw_value();
+            assert_eq!(SectionType::from(raw), section.sh_type);
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn true_section_index64() {
+        let mut file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let shoff = e.file_header.e_shoff as usize;
+        let symtab = e.find_section(".symtab").unwrap();
+        let comment = e.find_section(".comment").unwrap();
+        assert_eq!(e.true_section_index(0, &symtab).unwrap(), 0);
+        assert!(matches!(
+            e.true_section_index(1000, &symtab),
+            Err(Error::InvalidSectionIndex)
+        ));
+
+        // Turn the comment section into the extended index table of the
+        // symbol table and move the index of the third symbol into it
+        let sym = e.symtab_iter(symtab).nth(3).unwrap();
+        let sh = shoff + comment.sh_ndx * 0x40;
+        let st = symtab.sh_offset as usize + 3 * 0x18;
+        let entry = comment.sh_offset as usize + 3 * 0x04;
+        file[sh + 0x04..sh + 0x08].copy_from_slice(&0x12u32.to_le_bytes());
+        file[sh + 0x28..sh + 0x2c].copy_from_slice(&32u32.to_le_bytes());
+        file[st + 0x06..st + 0x08].copy_from_slice(&SHN_XINDEX.to_le_bytes());
+        file[entry..entry + 0x04]
+            .copy_from_slice(&(sym.st_shndx as u32).to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(
+            e.true_section_index(3, &symtab).unwrap(),
+            sym.st_shndx as u32
+        );
+        assert_eq!(
+            e.symtab_iter(symtab).nth(3).unwrap().section_index(),
+            SectionIndex::Xindex
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn symbol_versions64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let dynstr = e.find_section(".dynstr").unwrap();
+
+        // Every dynamic symbol has a version index
+        let versym = e.find_section_by_type(SectionType::ShtGnuVerSym);
+        let versions: std::vec::Vec<u16> = e
+            .versym_iter(versym.unwrap())
+            .map(|ver| ver.version_index())
+            .collect();
+        assert_eq!(versions, [0, 1, 3, 4, 1, 1, 3, 2, 2, 2, 2, 2, 2, 2, 2]);
+        assert!(e.versym_iter(versym.unwrap()).all(|ver| !ver.is_hidden()));
+        assert!(version::VersionSym(0x8002).is_hidden());
+
+        // A huge table size is bounded by the file size
+        let huge = SectionHeader { sh_size: u64::MAX, ..versym.unwrap() };
+        let room = e.elf.len() - huge.sh_offset as usize;
+        assert_eq!(e.versym_iter(huge).len(), room / 0x02);
+        assert_eq!(e.versym_iter(huge).count(), room / 0x02);
+
+        // The file and its version script are defined
+        let verdef = e.find_section_by_type(SectionType::ShtGnuVerDef);
+        let defs: std::vec::Vec<_> = e.verdef_iter(verdef.unwrap()).collect();
+        assert_eq!(defs.len(), 2);
+        assert_eq!(defs[0].vd_flags, version::VER_FLG_BASE);
+        assert_eq!((defs[1].vd_ndx, defs[1].vd_cnt), (2, 1));
+        let aux = defs[1].aux_iter().next().unwrap();
+        let name = e.ndx_name(aux.vda_name as usize, &dynstr);
+        assert_eq!(name, Some("VERS_1.0"));
+
+        // Each needed library has a single needed version
+        let verneed = e.find_section_by_type(SectionType::ShtGnuVerNeed);
+        let needs: std::vec::Vec<_> = e
+            .verneed_iter(verneed.unwrap())
+            .flat_map(|need| {
+                let file = e.ndx_name(need.vn_file as usize, &dynstr);
+                need.aux_iter().map(move |aux| (file, aux))
+            })
+            .map(|(file, aux)| {
+                let name = e.ndx_name(aux.vna_name as usize, &dynstr);
+                (file.unwrap(), name.unwrap(), aux.vna_other)
+            })
+            .collect();
+        assert_eq!(
+            needs,
+            [
+                ("ld-linux-x86-64.so.2", "GLIBC_2.3", 4),
+                ("libc.so.6", "GLIBC_2.2.5", 3),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn gnu_properties64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let properties: std::vec::Vec<_> = e
+            .gnu_properties()
+            .unwrap()
+            .map(|pr| (pr.pr_type, pr.pr_data))
+            .collect();
+        assert_eq!(
+            properties,
+            [
+                (note::GNU_PROPERTY_X86_ISA_1_NEEDED, &[1, 0, 0, 0][..]),
+                (note::GNU_PROPERTY_X86_FEATURE_2_USED, &[1, 0, 0, 0][..]),
+                (note::GNU_PROPERTY_X86_ISA_1_USED, &[0, 0, 0, 0][..]),
+            ]
+        );
+
+        // The shared library was linked without any properties
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.gnu_properties().is_none());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn linked_sections64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let dynsym = e.find_section(".dynsym").unwrap();
+        let dynstr = e.linked_section(&dynsym).unwrap();
+        assert_eq!(e.section_name(dynstr), Some(".dynstr"));
+        // The sh_info of a symbol table is not a section index
+        assert!(matches!(
+            e.info_section(&dynsym),
+            Err(Error::SectionNotFound)
+        ));
+
+        let rela_plt = e.find_section(".rela.plt").unwrap();
+        assert_eq!(e.linked_section(&rela_plt).unwrap(), dynsym);
+        // Full relro merges the PLT entries of the GOT into `.got`
+        let got = e.info_section(&rela_plt).unwrap();
+        assert_eq!(e.section_name(got), Some(".got"));
+
+        // The dynamic relocations are not tied to a section
+        let rela_dyn = e.find_section(".rela.dyn").unwrap();
+        assert!(matches!(
+            e.info_section(&rela_dyn),
+            Err(Error::SectionNotFound)
+        ));
+        let text = e.find_section(".text").unwrap();
+        assert!(matches!(
+            e.linked_section(&text),
+            Err(Error::SectionNotFound)
+        ));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn section_helpers64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let symtab = e.find_section(".symtab").unwrap();
+        assert_eq!(symtab.entry_count(), Some(36));
+        let shstrtab = e.find_section(".shstrtab").unwrap();
+        assert_eq!(shstrtab.entry_count(), None);
+        assert!(!shstrtab.is_empty());
+        assert!(e.section_iter().next().unwrap().is_empty());
+
+        let text = e.find_section(".text").unwrap();
+        assert!(text.contains_file_offset(0x1020));
+        assert!(text.contains_file_offset(0x1123));
+        assert!(!text.contains_file_offset(0x1124));
+        assert!(text.contains_vaddr(0x1119));
+        assert!(!text.contains_vaddr(0x101f));
+
+        // The bss section has an address but no data in the file
+        let bss = e.find_section(".bss").unwrap();
+        assert!(bss.contains_vaddr(0x4028));
+        assert!(!bss.contains_file_offset(0x3028));
+    }
+
+    #[test]
+    fn program_helpers32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let loads: std::vec::Vec<_> =
+            e.program_iter().of_type(ProgramType::PtLoad).collect();
+        assert!(loads[0].contains_vaddr(0x08048000));
+        assert!(!loads[0].contains_vaddr(0x08047fff));
+        assert!(loads[0].contains_file_offset(0));
+        assert!(!loads[1].contains_file_offset(0));
+
+        // Load segments of a valid file never overlap each other
+        for (i, ph) in loads.iter().enumerate() {
+            assert!(ph.overlaps_vaddr(ph) && ph.overlaps_file(ph));
+            for other in &loads[i + 1..] {
+                assert!(!ph.overlaps_vaddr(other));
+                assert!(!ph.overlaps_file(other));
+            }
+        }
+
+        // Segments ending at the end of the address space do not overflow
+        let mut ph = loads[0];
+        ph.p_vaddr = u64::MAX - 1;
+        ph.p_memsz = 0x10;
+        assert!(ph.contains_vaddr(u64::MAX));
+        assert!(!ph.contains_vaddr(0x08048000));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn containing_vaddr64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let ph = e.segment_containing_vaddr(0x1119).unwrap();
+        assert!(ph.p_flags.2 && ph.p_vaddr == 0x1000);
+        let sh = e.section_containing_vaddr(0x1119).unwrap();
+        assert_eq!(e.section_name(sh), Some(".text"));
+
+        // The bss is only in memory but still has an address
+        let sh = e.section_containing_vaddr(0x4028).unwrap();
+        assert_eq!(e.section_name(sh), Some(".bss"));
+        assert!(e.segment_containing_vaddr(0x4028).is_some());
+
+        // Non allocated sections start at zero but are never matched
+        assert!(e.section_containing_vaddr(0x10).is_none());
+        assert!(e.segment_containing_vaddr(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn parse_ident32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.file_header.e_ident[..], file[..0x10]);
+        assert_eq!(e.file_header.e_abi_version, 0);
+
+        // The abi version and the padding are kept as they are
+        file[0x08] = 3;
+        file[0x0f] = 0xaa;
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.file_header.e_abi_version, 3);
+        assert_eq!(e.file_header.e_ident[0x0f], 0xaa);
+
+        // A file too short for the identification is truncated
+        let e = Elf::new(&file[..0x0c]).parse();
+        assert!(matches!(e, Err(Error::TruncatedElf)));
+    }
+
+    #[test]
+    fn parse_options32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let strict = ParseOptions {
+            require_valid_class: true,
+            max_section_count: Some(5),
+            ..ParseOptions::default()
+        };
+        let e = Elf::new_with_options(file.as_slice(), strict).parse();
+        assert!(matches!(e, Err(Error::BadElf)));
+
+        // Break the magic number and both versions
+        file[0x00] = 0;
+        file[0x06] = 2;
+        file[0x14] = 2;
+        assert!(matches!(
+            Elf::new(file.as_slice()).parse(),
+            Err(Error::BadElf)
+        ));
+        let relaxed = ParseOptions {
+            ignore_bad_version: true,
+            ignore_bad_magic: true,
+            ..ParseOptions::default()
+        };
+        let e = Elf::new_with_options(file.as_slice(), relaxed).parse();
+        assert_eq!(e.unwrap().file_header.e_shnum, 6);
+
+        file[0x04] = 0;
+        let relaxed = ParseOptions {
+            require_valid_class: true,
+            ..relaxed
+        };
+        let e = Elf::new_with_options(file.as_slice(), relaxed).parse();
+        assert!(matches!(e, Err(Error::UnsupportedClass)));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn iter_segments64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.iter_load_segments().count(), 4);
+        assert_eq!(e.iter_note_segments().count(), 2);
+        assert_eq!(e.iter_dynamic_segments().count(), 1);
+        assert_eq!(e.iter_gnu_relro_segments().count(), 1);
+        assert!(e
+            .iter_load_segments()
+            .all(|ph| ph.p_type == ProgramType::PtLoad));
+    }
+
+    #[test]
+    fn parse_header_msb32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let lsb = FileHeader::new().parse(&file).unwrap();
+
+        // Byte swap every field of the file header after the identification
+        let mut header = file[..0x34].to_vec();
+        header[0x05] = 2;
+        let fields = [
+            (0x10, 2), (0x12, 2), (0x14, 4), (0x18, 4), (0x1c, 4), (0x20, 4),
+            (0x24, 4), (0x28, 2), (0x2a, 2), (0x2c, 2), (0x2e, 2), (0x30, 2),
+            (0x32, 2),
+        ];
+        for (offset, size) in fields {
+            header[offset..offset + size].reverse();
+        }
+
+        let msb = FileHeader::new().parse(&header).unwrap();
+        assert_eq!(msb.e_data, ElfData::ElfData2Msb);
+        assert_eq!(msb.e_type, ElfType::Executable);
+        assert_eq!(msb.e_type, lsb.e_type);
+        assert_eq!(msb.e_machine, file::ElfMachine::Intel80386);
+        assert_eq!(msb.e_entry, lsb.e_entry);
+        assert_eq!(msb.e_shoff, lsb.e_shoff);
+        assert_eq!(msb.e_shnum, lsb.e_shnum);
+
+        // Processor specific types use the whole upper byte range
+        header[0x10..0x12].copy_from_slice(&0xff42u16.to_be_bytes());
+        let msb = FileHeader::new().parse(&header).unwrap();
+        assert_eq!(msb.e_type, ElfType::CpuSpecific(0xff42));
+
+        // Big endian machines are matched on the value not the bytes
+        header[0x12..0x14].copy_from_slice(&0x0016u16.to_be_bytes());
+        let msb = FileHeader::new().parse(&header).unwrap();
+        assert_eq!(msb.e_machine, file::ElfMachine::S390);
+        header[0x12..0x14].copy_from_slice(&0x0102u16.to_be_bytes());
+        let msb = FileHeader::new().parse(&header).unwrap();
+        assert_eq!(msb.e_machine, file::ElfMachine::LoongArch);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn dynsym_iter64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let dynstr = e.dynsym_strtab().unwrap();
+        assert_eq!(e.section_name(dynstr), Some(".dynstr"));
+        let names: std::vec::Vec<_> = e
+            .dynsym_iter()
+            .unwrap()
+            .filter(|sym| sym.is_defined() && sym.is_function())
+            .filter_map(|sym| e.sym_name(sym, &dynstr))
+            .collect();
+        assert_eq!(
+            names,
+            ["protected_func", "call_puts", "weak_func", "exported_func"]
+        );
+
+        // Static executables have no dynamic symbols
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.dynsym_iter().is_none());
+        assert!(e.dynsym_strtab().is_none());
+    }
+
+    #[test]
+    fn strtab_iter32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let shstrtab = e.shtstrtab.unwrap();
+        let strings: std::vec::Vec<_> =
+            e.strtab_iter(&shstrtab).unwrap().collect();
+        assert_eq!(strings.first(), Some(&(0, "")));
+        // Every section name is found at its own offset
+        for section in e.section_iter().skip(1) {
+            let name = e.section_name(section).unwrap();
+            assert!(strings.contains(&(section.sh_name as usize, name)));
+        }
+
+        // Invalid and unterminated strings are skipped
+        let data = b"\0abc\0\xff\0def\0gh";
+        let strings: std::vec::Vec<_> = StrTabIterator::new(data).collect();
+        assert_eq!(strings, [(0, ""), (1, "abc"), (7, "def")]);
+    }
+
+    #[test]
+    fn section_name_bytes32() {
+        let mut file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let text = e.section_iter().nth(1).unwrap();
+        let name = e.section_name(text).unwrap().as_bytes().to_vec();
+        assert_eq!(e.section_name_bytes(text), Some(&name[..]));
+
+        // Corrupt the first byte of the name with an invalid UTF-8 byte
+        let shstrtab = e.shtstrtab.unwrap();
+        let offset = (shstrtab.sh_offset + text.sh_name as u64) as usize;
+        file[offset] = 0xff;
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.section_name(text), None);
+        let bytes = e.section_name_bytes(text).unwrap();
+        assert_eq!((bytes[0], &bytes[1..]), (0xff, &name[1..]));
+        assert_eq!(
+            e.ndx_name_bytes(text.sh_name as usize, &shstrtab),
+            Some(bytes)
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn all_symbols64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let dynstr = e.dynsym_strtab().unwrap();
+        let all: std::vec::Vec<_> = e.all_symbols().collect();
+        assert!(e.symtab_iter(symtab).eq(all[..36].iter().copied()));
+
+        // Only the dynamic imports are named differently in the static
+        // symbol table where their name includes the version
+        let extra: std::vec::Vec<_> = all[36..]
+            .iter()
+            .filter_map(|&sym| e.sym_name(sym, &dynstr))
+            .collect();
+        assert_eq!(extra, ["puts", "__tls_get_addr", "__cxa_finalize"]);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn exported_imported64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let dynstr = e.dynsym_strtab().unwrap();
+        let names = |symbols: &mut dyn Iterator<Item = SymTabEnt>| {
+            symbols
+                .filter_map(|sym| e.sym_name(sym, &dynstr))
+                .collect::<std::vec::Vec<_>>()
+        };
+        assert_eq!(
+            names(&mut e.exported_symbols()),
+            [
+                "exported_data",
+                "protected_func",
+                "call_puts",
+                "VERS_1.0",
+                "tls_counter",
+                "ifn",
+                "weak_func",
+                "exported_func",
+            ]
+        );
+        assert_eq!(
+            names(&mut e.imported_symbols()),
+            [
+                "_ITM_deregisterTMCloneTable",
+                "puts",
+                "__tls_get_addr",
+                "__gmon_start__",
+                "_ITM_registerTMCloneTable",
+                "__cxa_finalize",
+            ]
+        );
+
+        // Static executables have no dynamic symbols to export
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.exported_symbols().count(), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn size_hint64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let rela_dyn = e.find_section(".rela.dyn").unwrap();
+        let mut relocations = e.rela_iter(rela_dyn);
+        assert_eq!(relocations.len(), 9);
+        relocations.next();
+        assert_eq!(relocations.size_hint(), (8, Some(8)));
+        assert_eq!(relocations.count(), 8);
+
+        // The dynamic section is padded with null entries
+        let dynamic = e.find_section_by_type(SectionType::ShtDynamic);
+        let entries = e.dyn_iter(dynamic.unwrap());
+        assert_eq!(entries.size_hint(), (0, Some(36)));
+        assert!(entries.count() < 36);
+
+        // Huge table sizes are bounded by the file size
+        let huge = SectionHeader { sh_size: u64::MAX, ..rela_dyn };
+        let room = e.elf.len() - rela_dyn.sh_offset as usize;
+        assert_eq!(e.rela_iter(huge).len(), room / 0x18);
+        assert_eq!(e.rela_iter(huge).count(), room / 0x18);
+        let huge = SectionHeader { sh_size: u64::MAX, ..dynamic.unwrap() };
+        let room = e.elf.len() - huge.sh_offset as usize;
+        assert_eq!(e.dyn_iter(huge).size_hint(), (0, Some(room / 0x10)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", target_pointer_width = "64"))]
+    fn section_name_index64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let index = e.build_section_index();
+        assert_eq!(index.len(), 35);
+        for name in ["", ".text", ".symtab", ".dynamic", ".shstrtab"] {
+            let sh = e.find_section_fast(name, &index);
+            assert_eq!(sh, e.find_section(name));
+        }
+        assert_eq!(e.find_section_fast(".no_such_section", &index), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn apply_rela_callback64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let rela_dyn = e.find_section(".rela.dyn").unwrap();
+        let dynsym = e.find_section(".dynsym").unwrap();
+        let base = 0x7f0000000000;
+        let mut patches = std::vec::Vec::new();
+        e.apply_rela_callback(rela_dyn, dynsym, base, |at, ty, value, addend| {
+            patches.push((at - base, ty, value, addend))
+        })
+        .unwrap();
+        assert_eq!(patches.len(), 9);
+        // R_X86_64_RELATIVE has no symbol
+        assert_eq!(patches[0], (0x3d58, 8, 0, 0x1110));
+        // R_X86_64_GLOB_DAT of an undefined weak symbol
+        assert_eq!(patches[3], (0x3fd0, 6, 0, 0));
+        // R_X86_64_DTPMOD64 of the defined thread local symbol which is
+        // an offset in the TLS block and not moved by the base
+        assert_eq!(patches[5], (0x3fe0, 16, 0, 0));
+
+        // The relocation symbols must be in the symbol table
+        let mut symtab = dynsym;
+        symtab.sh_size = symtab.sh_entsize * 2;
+        let mut calls = 0;
+        let result =
+            e.apply_rela_callback(rela_dyn, symtab, 0, |_, _, _, _| {
+                calls += 1
+            });
+        assert!(matches!(result, Err(Error::BadElf)));
+
+        // The error is found before any relocation is applied
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn machine_flags32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let mut header = FileHeader::new().parse(&file).unwrap();
+        assert_eq!(header.arm_eabi_version(), None);
+        assert_eq!(header.riscv_float_abi(), None);
+        assert_eq!(header.mips_is_pic(), None);
+
+        // Hard float EABI version 5 with a Thumb entry point
+        header.e_machine = file::ElfMachine::Arm;
+        header.e_flags = 0x05000400;
+        header.e_entry = 0x10001;
+        assert_eq!(header.arm_eabi_version(), Some(5));
+        assert_eq!(header.arm_is_thumb_entry(), Some(true));
+        assert_eq!(header.arm_float_abi(), Some(file::ArmFloatAbi::Hard));
+
+        // The usual RV64GC double float calling convention
+        header.e_machine = file::ElfMachine::Riscv;
+        header.e_flags = 0x0005;
+        let abi = header.riscv_float_abi();
+        assert_eq!(abi, Some(file::RiscvFloatAbi::Double));
+        assert_eq!(header.riscv_is_rvc(), Some(true));
+
+        // O32 position independent code for MIPS32 release 2
+        header.e_machine = file::ElfMachine::Mips;
+        header.e_flags = 0x70001007;
+        assert_eq!(header.mips_abi(), Some(0x1000));
+        assert_eq!(header.mips_arch(), Some(0x70000000));
+        assert_eq!(header.mips_is_pic(), Some(true));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn rela_type_x86_64() {
+        use relocation::x86_64::RelocType;
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let rela = e.find_section(".rela.dyn").unwrap();
+        let types: std::vec::Vec<_> = e
+            .rela_iter(rela)
+            .map(|rela| rela.r_type_x86_64().unwrap())
+            .collect();
+        assert_eq!(types[0], RelocType::Relative);
+        assert_eq!(types[3], RelocType::GlobDat);
+        assert_eq!(types[5], RelocType::DtpMod64);
+        assert_eq!(types[6].raw_value(), 0x11);
+        let rela = e.find_section(".rela.plt").unwrap();
+        let jump_slot = e.rela_iter(rela).next().unwrap();
+        assert_eq!(jump_slot.r_type_x86_64(), Some(RelocType::JumpSlot));
+        assert_eq!(RelocType::from(0x1000), RelocType::Unknown(0x1000));
+        // Entries parsed without a machine have no x86-64 type
+        assert_eq!(relocation::RelaEnt::new().r_type_x86_64(), None);
+    }
+
+    #[test]
+    fn rela_type_per_machine() {
+        use file::ElfMachine;
+        use relocation::{aarch64, arm, riscv};
+        // An R_AARCH64_JUMP_SLOT followed by an R_ARM_JUMP_SLOT relocation
+        // against symbol 2 which are read for each machine
+        let mut rela = [0u8; 0x30];
+        rela[0x08..0x10].copy_from_slice(&0x0000000200000402u64.to_le_bytes());
+        rela[0x20..0x28].copy_from_slice(&0x0000000200000016u64.to_le_bytes());
+        let sh = SectionHeader {
+            sh_size: rela.len() as u64,
+            sh_entsize: 0x18,
+            ..SectionHeader::new()
+        };
+        let parse = |machine| {
+            relocation::RelaIterator::new(
+                sh,
+                ElfClass::Class64,
+                machine,
+                ElfData::ElfData2Lsb,
+                &rela,
+            )
+            .collect::<std::vec::Vec<_>>()
+        };
+        let relas = parse(ElfMachine::Aarch64);
+        assert_eq!(
+            relas[0].r_type_aarch64(),
+            Some(aarch64::RelocType::JumpSlot)
+        );
+        assert_eq!(relas[0].r_type_arm(), None);
+        assert_eq!(relas[0].r_type_x86_64(), None);
+        let relas = parse(ElfMachine::Arm);
+        assert_eq!(relas[1].r_type_arm(), Some(arm::RelocType::JumpSlot));
+        assert_eq!(
+            relas[0].r_type_arm(),
+            Some(arm::RelocType::Unknown(0x402))
+        );
+        let relas = parse(ElfMachine::Riscv);
+        assert_eq!(
+            relas[0].r_type_riscv(),
+            Some(riscv::RelocType::Unknown(0x402))
+        );
+        assert_eq!(relas[1].r_sym(), 2);
+        assert_eq!(riscv::RelocType::from(5), riscv::RelocType::JumpSlot);
+        assert_eq!(aarch64::RelocType::TlsDesc.raw_value(), 1031);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn eh_frame_hdr64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let hdr = e.eh_frame_hdr().unwrap();
+        assert_eq!(hdr.version, 1);
+        assert_eq!(hdr.eh_frame_ptr_enc, 0x1b);
+        assert_eq!(hdr.table_enc, 0x3b);
+        let eh_frame = e.find_section(".eh_frame").unwrap();
+        assert_eq!(hdr.eh_frame_ptr, eh_frame.sh_addr);
+        assert_eq!(hdr.fde_count, 2);
+        assert_eq!(hdr.entry(0), Some((0x1020, 0x2038)));
+        assert_eq!(hdr.entry(2), None);
+        // Addresses before the first FDE are not covered
+        assert_eq!(hdr.find_fde(0x101f), None);
+        assert_eq!(hdr.find_fde(0x1020), Some(0x18));
+        assert_eq!(hdr.find_fde(0x1118), Some(0x18));
+        assert_eq!(hdr.find_fde(0x1119), Some(0x30));
+        assert_eq!(hdr.find_fde(u64::MAX), Some(0x30));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn debug_sections64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.has_debug_info());
+        assert_eq!(e.debug_sections().count(), 7);
+        assert_eq!(
+            e.debug_sections().next(),
+            e.find_section(".debug_aranges")
+        );
+        assert_eq!(e.debug_info_section().unwrap().sh_size, 0x585);
+        assert_eq!(e.debug_abbrev_section().unwrap().sh_size, 0x198);
+        assert_eq!(e.debug_line_section().unwrap().sh_size, 0x1da);
+        assert_eq!(e.debug_str_section().unwrap().sh_size, 0x471);
+
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(!e.has_debug_info());
+        assert_eq!(e.debug_sections().count(), 0);
+    }
+
+    #[test]
+    fn gnu_debuglink() {
+        // File name padded to the next 4 bytes followed by the CRC32
+        let mut link = [0u8; 0x10];
+        link[..0x09].copy_from_slice(b"test.dbg\0");
+        link[0x0c..0x10].copy_from_slice(&0xdeadbeefu32.to_le_bytes());
+        let link =
+            section::GnuDebugLink::parse(&link, ElfData::ElfData2Lsb).unwrap();
+        assert_eq!(link.filename, "test.dbg");
+        assert_eq!(link.crc, 0xdeadbeef);
+        // The CRC is missing after the padding
+        let link = b"test\0\0\0\0";
+        assert!(matches!(
+            section::GnuDebugLink::parse(link, ElfData::ElfData2Lsb),
+            Err(Error::TruncatedElf)
+        ));
+
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.gnu_debuglink(), None);
+    }
+
+    #[test]
+    fn modinfo_iter() {
+        let modinfo = b"license=GPL\0\0\0\0author=A=B\0intree\0desc";
+        let mut it = section::ModinfoIterator::new(modinfo);
+        assert_eq!(it.next(), Some(("license", "GPL")));
+        // Only the first `=` splits the key from the value
+        assert_eq!(it.next(), Some(("author", "A=B")));
+        assert_eq!(it.next(), Some(("intree", "")));
+        // The unterminated tail is not an entry
+        assert_eq!(it.next(), None);
+
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(!e.is_kernel_module());
+        assert!(e.kernel_module_info().is_none());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn core_dump_notes64() {
+        use note::CoreNoteType;
+        let file = std::fs::read("./tests/elf_test_core64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.is_core_dump());
+        assert!(!e.is_executable() && !e.is_relocatable_object());
+        let notes: std::vec::Vec<_> = e.core_dump_notes().unwrap().collect();
+        let types: std::vec::Vec<_> =
+            notes.iter().map(|note| note.note_type).collect();
+        assert_eq!(
+            types[..6],
+            [
+                CoreNoteType::Prstatus,
+                CoreNoteType::Prpsinfo,
+                CoreNoteType::Siginfo,
+                CoreNoteType::Auxv,
+                CoreNoteType::File,
+                CoreNoteType::Other(note::NT_PRFPREG),
+            ]
+        );
+        assert_eq!(notes[0].note.desc.len(), 336);
+        // The process was killed by SIGSEGV
+        assert_eq!(notes[2].note.desc[0], 11);
+        assert_eq!(&notes[1].note.desc[0x28..0x2d], b"crash");
+        // The XSAVE state note of the `LINUX` owner is not a core note
+        assert_eq!(notes[6].note.name, b"LINUX\0");
+        assert_eq!(notes[6].note_type, CoreNoteType::Other(0x202));
+
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.core_dump_notes().is_none());
+        assert!(!e.is_core_dump());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn emit_round_trip() {
+        for path in [
+            "./tests/elf_test32",
+            "./tests/elf_test64",
+            "./tests/elf_be32",
+            "./tests/elf_be64",
+        ] {
+            let file = std::fs::read(path)
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            let (class, data) = (e.file_header.e_class, e.file_header.e_data);
+            let hdr = &e.file_header;
+
+            // Every header is written back to its original bytes
+            let mut buf = [0u8; 0x40];
+            let size = hdr.e_ehsize as usize;
+            hdr.emit(&mut buf[..size], class, data).unwrap();
+            assert_eq!(buf[..size], file[..size]);
+
+            let size = hdr.e_shentsize as usize;
+            for (ndx, sh) in e.section_iter().enumerate() {
+                let offset = hdr.e_shoff as usize + ndx * size;
+                sh.emit(&mut buf[..size], class, data).unwrap();
+                assert_eq!(buf[..size], file[offset..offset + size]);
+            }
+
+            let size = hdr.e_phentsize as usize;
+            for (ndx, ph) in e.program_iter().enumerate() {
+                let offset = hdr.e_phoff as usize + ndx * size;
+                ph.emit(&mut buf[..size], class, data).unwrap();
+                assert_eq!(buf[..size], file[offset..offset + size]);
+            }
+
+            let symtab = e.find_section_by_type(SectionType::ShtSymTab);
+            let symtab = symtab.unwrap();
+            let size = symtab.sh_entsize as usize;
+            for (ndx, sym) in e.symtab_iter(symtab).enumerate() {
+                let offset = symtab.sh_offset as usize + ndx * size;
+                sym.emit(&mut buf[..size], class, data).unwrap();
+                assert_eq!(buf[..size], file[offset..offset + size]);
+            }
+        }
+
+        // The buffer must hold the whole header
+        let sh = SectionHeader::new();
+        let mut buf = [0u8; 0x28];
+        sh.emit(&mut buf, ElfClass::Class32, ElfData::ElfData2Msb).unwrap();
+        assert!(matches!(
+            sh.emit(&mut buf, ElfClass::Class64, ElfData::ElfData2Msb),
+            Err(Error::OffsetCalculationFailure)
+        ));
+        // 32-bit fields can not hold 64-bit values
+        let sh = SectionHeader { sh_addr: 1 << 32, ..SectionHeader::new() };
+        assert!(matches!(
+            sh.emit(&mut buf, ElfClass::Class32, ElfData::ElfData2Lsb),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn emit_raw_types() {
+        let mut file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let shoff = e.file_header.e_shoff as usize;
+        let phoff = e.file_header.e_phoff as usize;
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let sym = symtab.sh_offset as usize + 0x18;
+
+        // Types in the reserved ranges and types without a name
+        file[0x10..0x12].copy_from_slice(&0xfe42u16.to_le_bytes());
+        file[0x12..0x14].copy_from_slice(&0x1234u16.to_le_bytes());
+        file[shoff + 0x44..shoff + 0x48]
+            .copy_from_slice(&0x6ffffff0u32.to_le_bytes());
+        file[shoff + 0x84..shoff + 0x88]
+            .copy_from_slice(&0x15u32.to_le_bytes());
+        file[phoff..phoff + 0x04]
+            .copy_from_slice(&0x6fff1234u32.to_le_bytes());
+        file[phoff + 0x38..phoff + 0x3c]
+            .copy_from_slice(&0x80000000u32.to_le_bytes());
+        file[sym + 0x04] = 0xb7;
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let (class, data) = (e.file_header.e_class, e.file_header.e_data);
+
+        // The parsed values are written back unchanged
+        let hdr = &e.file_header;
+        assert_eq!(hdr.e_type, ElfType::OsSpecific(0xfe42));
+        assert_eq!(hdr.e_machine, file::ElfMachine::UnDefined(0x1234));
+        let mut buf = [0u8; 0x40];
+        hdr.emit(&mut buf, class, data).unwrap();
+        assert_eq!(buf, file[..0x40]);
+
+        let sh = e.section_at_index(1).unwrap();
+        assert_eq!(sh.sh_type, SectionType::ShtOs(0x6ffffff0));
+        sh.emit(&mut buf, class, data).unwrap();
+        assert_eq!(buf, file[shoff + 0x40..shoff + 0x80]);
+        let sh = e.section_at_index(2).unwrap();
+        assert_eq!(sh.sh_type, SectionType::Unknown(0x15));
+        sh.emit(&mut buf, class, data).unwrap();
+        assert_eq!(buf, file[shoff + 0x80..shoff + 0xc0]);
+
+        let ph = e.program_header_at_index(0).unwrap();
+        assert_eq!(ph.p_type, ProgramType::PtOs(0x6fff1234));
+        ph.emit(&mut buf[..0x38], class, data).unwrap();
+        assert_eq!(buf[..0x38], file[phoff..phoff + 0x38]);
+        let ph = e.program_header_at_index(1).unwrap();
+        assert_eq!(ph.p_type, ProgramType::Unknown(0x80000000));
+        ph.emit(&mut buf[..0x38], class, data).unwrap();
+        assert_eq!(buf[..0x38], file[phoff + 0x38..phoff + 0x70]);
+
+        let symbol = e.symtab_iter(symtab).nth(1).unwrap();
+        assert_eq!(symbol.st_info, SymType::Unknown(7));
+        assert_eq!(symbol.st_bind, SymBinding::Unknown(11));
+        symbol.emit(&mut buf[..0x18], class, data).unwrap();
+        assert_eq!(buf[..0x18], file[sym..sym + 0x18]);
+    }
+
+    #[test]
+    fn gnu_build_attributes() {
+        use note::{GnuBuildAttrName, GnuBuildAttrValue};
+        // Append a 4-byte aligned note to the section data
+        fn push(
+            notes: &mut std::vec::Vec<u8>,
+            ty: u32,
+            name: &[u8],
+            desc: &[u8],
+        ) {
+            notes.extend((name.len() as u32).to_le_bytes());
+            notes.extend((desc.len() as u32).to_le_bytes());
+            notes.extend(ty.to_le_bytes());
+            for field in [name, desc] {
+                notes.extend(field);
+                notes.resize(notes.len().next_multiple_of(4), 0);
+            }
+        }
+        let mut range = std::vec::Vec::new();
+        range.extend(0x1000u64.to_le_bytes());
+        range.extend(0x1100u64.to_le_bytes());
+        let mut notes = std::vec::Vec::new();
+        push(&mut notes, 0x100, b"GA$\x013p9\0", &range);
+        push(&mut notes, 0x101, b"GA*\x04\x10\x02\0", &[]);
+        push(&mut notes, 0x100, b"GA+GLIBCXX_ASSERTIONS\0", &[]);
+        push(&mut notes, 0x100, b"GA*GOW\0\x02\0", &[]);
+        // A GNU build-id note is not a build attribute
+        push(&mut notes, 3, b"GNU\0", &[0xaa; 4]);
+        push(&mut notes, 0x100, b"GA!\x03\0", &[]);
+
+        let it = note::GnuBuildAttrIterator::new(
+            note::NoteIterator::new(
+                0,
+                notes.len() as u64,
+                4,
+                ElfData::ElfData2Lsb,
+                &notes,
+            ),
+            ElfClass::Class64,
+            ElfData::ElfData2Lsb,
+        );
+        let attrs: std::vec::Vec<_> = it.collect();
+        assert_eq!(attrs.len(), 5);
+        assert_eq!(attrs[0].name, GnuBuildAttrName::Version);
+        assert_eq!(attrs[0].value, GnuBuildAttrValue::String("3p9"));
+        assert_eq!(attrs[0].range, Some((0x1000, 0x1100)));
+        assert_eq!(attrs[1].n_type, note::NT_GNU_BUILD_ATTRIBUTE_FUNC);
+        assert_eq!(attrs[1].name, GnuBuildAttrName::StackSize);
+        assert_eq!(attrs[1].value, GnuBuildAttrValue::Numeric(0x0210));
+        assert_eq!(attrs[1].range, None);
+        assert_eq!(
+            attrs[2].name,
+            GnuBuildAttrName::Other("GLIBCXX_ASSERTIONS")
+        );
+        assert_eq!(attrs[2].value, GnuBuildAttrValue::Bool(true));
+        assert_eq!(attrs[3].name, GnuBuildAttrName::Other("GOW"));
+        assert_eq!(attrs[3].value, GnuBuildAttrValue::Numeric(2));
+        assert_eq!(attrs[4].name, GnuBuildAttrName::Relro);
+        assert_eq!(attrs[4].value, GnuBuildAttrValue::Bool(false));
+
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.gnu_build_attributes().is_none());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn tls_template64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let tls = e.tls_template().unwrap();
+        // The initial value of `tls_counter`
+        assert_eq!(tls.data, 1u32.to_le_bytes());
+        assert_eq!((tls.zero_size, tls.align), (0, 4));
+        // The executable has no thread-local storage
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.tls_template(), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn static_pie_reloc_addresses64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        // Only the R_X86_64_RELATIVE entries of `.rela.dyn` are relative
+        let addrs: std::vec::Vec<_> =
+            e.static_pie_reloc_addresses(0x10000).collect();
+        assert_eq!(addrs, [0x13d58, 0x13d60, 0x14000]);
+        // Addresses moved past the end of the address space are skipped
+        let base = u64::MAX - 0x3d5f;
+        let addrs: std::vec::Vec<_> =
+            e.static_pie_reloc_addresses(base).collect();
+        assert_eq!(addrs, [u64::MAX - 7]);
+        // The 32-bit static executable has no relocations
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.static_pie_reloc_addresses(0).count(), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn plt_entries64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.plt_entry_size(), Some(16));
+        // One stub for each of the two `.rela.plt` relocations
+        let stubs: std::vec::Vec<_> = e.plt_entries_iter().unwrap().collect();
+        assert_eq!(stubs, [(0, 0x1030), (1, 0x1040)]);
+        // The executable has no `.plt` section
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.plt_entries_iter().is_none());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn got_entries64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        // Full relro merges `.got.plt` into `.got`
+        assert_eq!(e.got_plt_section(), None);
+        let got = e.got_section().unwrap();
+        let entries: std::vec::Vec<_> = e.got_entries_iter(&got).collect();
+        assert_eq!(entries.len(), 11);
+        // The `_DYNAMIC` address then the lazy binding stubs of the PLT
+        assert_eq!(entries[0], 0x3d68);
+        assert_eq!(entries[3..5], [0x1036, 0x1046]);
+
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let got_plt = e.got_plt_section().unwrap();
+        let entries: std::vec::Vec<_> = e.got_entries_iter(&got_plt).collect();
+        assert_eq!(entries, [0x3e38, 0, 0]);
+    }
+
+    #[test]
+    fn sym_type_gnu_ifunc() {
+        assert_eq!(SymType::from(10), SymType::GnuIfunc);
+        // The binding in the high nibble does not change the type
+        assert_eq!(SymType::from(0x1a), SymType::GnuIfunc);
+        assert_eq!(SymType::GnuIfunc.raw_value(), 10);
+        assert_eq!(SymType::from(12), SymType::HiOs);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn ifunc_symbols64() {
+        let mut file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.ifunc_symbols().count(), 0);
+
+        // Turn the global `main` symbol at index 31 into an indirect function
+        let symtab = e.find_section_by_type(SectionType::ShtSymTab).unwrap();
+        let info = symtab.sh_offset as usize + 31 * 0x18 + 0x04;
+        file[info] = 0x1a;
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let ifuncs: std::vec::Vec<_> = e.ifunc_symbols().collect();
+        assert_eq!(ifuncs.len(), 1);
+        assert_eq!(ifuncs[0].st_value, 0x1119);
+        assert!(ifuncs[0].is_global() && !ifuncs[0].is_function());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn common_sections64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.text_section().unwrap().sh_addr, 0x1020);
+        assert_eq!(e.rodata_section().unwrap().sh_addr, 0x2000);
+        assert_eq!(e.data_section().unwrap().sh_addr, 0x4018);
+        assert_eq!(e.bss_section().unwrap().sh_size, 0x08);
+        assert_eq!(e.text_data().unwrap().len(), 0x104);
+        assert_eq!(e.rodata_data().unwrap(), [0x01, 0x00, 0x02, 0x00]);
+        assert_eq!(e.data_data().unwrap().len(), 0x10);
+        // `.bss` takes no space in the file
+        assert!(e.bss_data().unwrap().is_empty());
+    }
+
+    #[test]
+    fn common_sections32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.text_data().unwrap().len(), 0x1f);
+        assert!(e.rodata_section().is_none());
+        assert!(matches!(e.rodata_data(), Err(Error::SectionNotFound)));
+        assert!(matches!(e.bss_data(), Err(Error::SectionNotFound)));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn all_relocations_so64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let names: std::vec::Vec<_> = e
+            .all_rela_sections()
+            .filter_map(|sh| e.section_name(sh))
+            .collect();
+        assert_eq!(names, [".rela.dyn", ".rela.plt"]);
+        assert_eq!(e.all_rel_sections().count(), 0);
+        assert_eq!(e.all_rel_entries().count(), 0);
+
+        // `.rela.dyn` entries come before the `.rela.plt` ones
+        let entries: std::vec::Vec<_> = e.all_rela_entries().collect();
+        assert_eq!(entries.len(), 11);
+        assert_eq!(entries[0].r_offset, 0x3d58);
+        assert_eq!(
+            entries[9].r_type_x86_64(),
+            Some(x86_64::RelocType::JumpSlot)
+        );
+    }
+
+    #[test]
+    fn all_relocations32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.all_rela_sections().count(), 0);
+        assert_eq!(e.all_rela_entries().count(), 0);
+        assert_eq!(e.all_rel_entries().count(), 0);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn entry_point64() {
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.total_load_size(), 0x5f8 + 0x131 + 0x74 + 0x208);
+        let ph = e.entry_point_segment().unwrap();
+        assert_eq!(ph.p_vaddr, 0x1000);
+        assert!(ph.p_flags.is_exec());
+        let sh = e.entry_point_section().unwrap();
+        assert_eq!(e.section_name(sh), Some(".text"));
+    }
+
+    #[test]
+    fn entry_point32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.total_load_size(), 0x94 + 0x1f + 0x0c);
+        assert_eq!(e.entry_point_segment().unwrap().p_vaddr, 0x08049000);
+        let sh = e.entry_point_section().unwrap();
+        assert_eq!(e.section_name(sh), Some(".text"));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn no_entry_point_so64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.file_header.e_entry, 0);
+        assert!(e.entry_point_segment().is_none());
+        assert!(e.entry_point_section().is_none());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn hash_sections64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let gnu = e.gnu_hash_section().unwrap();
+        let sysv = e.sysv_hash_section().unwrap();
+        assert_eq!(e.section_name(gnu), Some(".gnu.hash"));
+        assert_eq!(e.section_name(sysv), Some(".hash"));
+
+        // Every lookup agrees including on the symbols before `symoffset`
+        let dynsym = e.find_section_by_type(SectionType::ShtDynSym).unwrap();
+        let dynstr = e.section_at_index(dynsym.sh_link as usize).unwrap();
+        let symbols = e.symtab_iter(dynsym);
+        let table = e.gnu_hash_table().unwrap();
+        let sysv = e.sysv_hash_table().unwrap();
+        let lookups: [&dyn SymbolLookup; 3] = [
+            &GnuHashLookup::new(table, symbols, dynstr, &e),
+            &SysVHashLookup::new(sysv, symbols, dynstr, &e),
+            &LinearLookup::new(symbols, dynstr, &e),
+        ];
+        for lookup in lookups {
+            let sym = lookup.lookup("exported_func").unwrap();
+            assert_eq!(sym.st_value, 0x111f);
+            assert!(lookup.lookup("puts").is_some());
+            assert_eq!(lookup.lookup("local_helper"), None);
+            assert_eq!(lookup.lookup("missing_func"), None);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", target_pointer_width = "64"))]
+    fn fastest_symbol_lookup64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let lookup = e.fastest_symbol_lookup().unwrap();
+        assert_eq!(lookup.lookup("exported_func").unwrap().st_value, 0x111f);
+        assert_eq!(lookup.lookup("call_puts").unwrap().st_size, 21);
+        assert!(lookup.lookup("puts").is_some());
+        assert_eq!(lookup.lookup("local_helper"), None);
+
+        // The static executable has no dynamic symbol table
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.fastest_symbol_lookup().is_none());
+    }
+
+    #[test]
+    fn program_header_at_index32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        for (ndx, ph) in e.program_iter().enumerate() {
+            assert_eq!(e.program_header_at_index(ndx).unwrap(), ph);
+        }
+        let text = e.program_header_at_index(1).unwrap();
+        assert_eq!(text.p_vaddr, 0x08049000);
+        assert!(matches!(
+            e.program_header_at_index(e.file_header.e_phnum),
+            Err(Error::InvalidProgramHeaderIndex)
+        ));
+
+        // A table running past the end of the file is truncated
+        let phoff = e.file_header.e_phoff as usize;
+        let e = Elf::new(&file[..phoff + 0x30]).parse().unwrap();
+        assert!(e.program_header_at_index(0).is_ok());
+        assert!(matches!(
+            e.program_header_at_index(1),
+            Err(Error::TruncatedElf)
+        ));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn overlapping_sections64() {
+        let mut file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(!e.has_overlapping_sections());
+        assert!(!e.has_overlapping_segments());
+
+        // Move the `.data` section over the `.text` one
+        let data = e.data_section().unwrap();
+        let shoff = e.file_header.e_shoff as usize;
+        let offset = shoff + data.sh_ndx * 0x40 + 0x18;
+        file[offset..offset + 0x08].copy_from_slice(&0x1020u64.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let pairs: std::vec::Vec<_> = e
+            .overlapping_sections()
+            .map(|(sh, other)| (e.section_name(sh), e.section_name(other)))
+            .collect();
+        assert_eq!(pairs, [(Some(".text"), Some(".data"))]);
+        assert!(!e.has_overlapping_segments());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn overlapping_segments64() {
+        let mut file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+
+        // Move the read-only data segment over the code one
+        let ndx = e
+            .program_iter()
+            .position(|ph| {
+                ph.p_type == ProgramType::PtLoad && ph.p_vaddr == 0x2000
+            })
+            .unwrap();
+        let offset = e.file_header.e_phoff as usize + ndx * 0x38 + 0x08;
+        file[offset..offset + 0x08].copy_from_slice(&0x1100u64.to_le_bytes());
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let pairs: std::vec::Vec<_> = e
+            .overlapping_segments()
+            .map(|(ph, other)| (ph.p_vaddr, other.p_vaddr))
+            .collect();
+        assert_eq!(pairs, [(0x1000, 0x2000)]);
+        assert!(e.has_overlapping_segments());
+    }
+
+    #[test]
+    fn class_and_encoding() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.is_32bit() && !e.is_64bit());
+        assert!(e.is_little_endian() && !e.is_big_endian());
+        assert_eq!(e.pointer_size(), 4);
+
+        let file = std::fs::read("./tests/elf_test64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert!(e.is_64bit() && !e.is_32bit());
+        assert!(e.is_little_endian());
+        assert_eq!(e.pointer_size(), 8);
+    }
+
+    /// Returns a file made of the file header, the program header table and
+    /// the section header table followed by `tail` zero bytes of data
+    fn synthetic_elf(
+        class: ElfClass,
+        data: ElfData,
+        segments: &[ProgramHeader],
+        sections: &[SectionHeader],
+        tail: usize,
+    ) -> std::vec::Vec<u8> {
+        let (ehsize, phentsize, shentsize) = match class {
+            ElfClass::Class32 => (0x34, 0x20, 0x28),
+            _ => (0x40, 0x38, 0x40),
+        };
+        let phoff = ehsize;
+        let shoff = phoff + segments.len() * phentsize;
+        let mut file =
+            std::vec![0u8; shoff + sections.len() * shentsize + tail];
+
+        let mut ident = [0u8; 16];
+        ident[0x00..0x04].copy_from_slice(b"\x7fELF");
+        ident[0x06] = 1;
+        let hdr = FileHeader {
+            e_ident: ident,
+            e_type: ElfType::Executable,
+            e_phoff: if segments.is_empty() { 0 } else { phoff as u64 },
+            e_shoff: if sections.is_empty() { 0 } else { shoff as u64 },
+            e_ehsize: ehsize as u16,
+            e_phentsize: phentsize as u16,
+            e_phnum: segments.len(),
+            e_shentsize: shentsize as u16,
+            e_shnum: sections.len(),
+            ..FileHeader::new()
+        };
+        hdr.emit(&mut file, class, data).unwrap();
+        for (ndx, ph) in segments.iter().enumerate() {
+            let offset = phoff + ndx * phentsize;
+            ph.emit(&mut file[offset..], class, data).unwrap();
+        }
+        for (ndx, sh) in sections.iter().enumerate() {
+            let offset = shoff + ndx * shentsize;
+            sh.emit(&mut file[offset..], class, data).unwrap();
+        }
+        file
+    }
+
+    /// Strategy of the supported elf classes and data encodings
+    fn any_layout() -> impl proptest::strategy::Strategy<
+        Value = (ElfClass, ElfData),
+    > {
+        use proptest::prelude::*;
+        (
+            prop_oneof![Just(ElfClass::Class32), Just(ElfClass::Class64)],
+            prop_oneof![
+                Just(ElfData::ElfData2Lsb),
+                Just(ElfData::ElfData2Msb),
+            ],
+        )
+    }
+
+    /// Strategy of the file ranges which are either small enough to be
+    /// partly inside the synthetic files or anywhere in a 32-bit file
+    fn any_range() -> impl proptest::strategy::Strategy<Value = (u64, u64)> {
+        use proptest::prelude::*;
+        prop_oneof![
+            (0u64..0x1000, 0u64..0x1000),
+            (any::<u32>(), any::<u32>())
+                .prop_map(|(start, size)| (start as u64, size as u64)),
+        ]
+    }
+
+    /// Strategy of the section headers which fit the 32-bit layout
+    fn any_section() -> impl proptest::strategy::Strategy<
+        Value = SectionHeader,
+    > {
+        use proptest::prelude::*;
+        (any::<[u32; 8]>(), any_range()).prop_map(|(raw, range)| {
+            SectionHeader {
+                sh_name: raw[0],
+                sh_type: SectionType::from(raw[1]),
+                sh_flags: SectionFlags::from_bits(raw[2] as u64),
+                sh_addr: raw[3] as u64,
+                sh_offset: range.0,
+                sh_size: range.1,
+                sh_link: raw[4],
+                sh_info: raw[5],
+                sh_addralign: raw[6] as u64,
+                sh_entsize: raw[7] as u64,
+                sh_ndx: 0,
+            }
+        })
+    }
+
+    /// Strategy of the program headers which fit the 32-bit layout
+    fn any_segment() -> impl proptest::strategy::Strategy<
+        Value = ProgramHeader,
+    > {
+        use proptest::prelude::*;
+        (any::<[u32; 4]>(), any::<[bool; 3]>(), any_range()).prop_map(
+            |(raw, perm, range)| ProgramHeader {
+                p_type: ProgramType::from(raw[0]),
+                p_flags: program::Perm(perm[0], perm[1], perm[2]),
+                p_offset: range.0,
+                p_vaddr: raw[1] as u64,
+                p_paddr: raw[2] as u64,
+                p_filesz: range.1,
+                p_memsz: range.1,
+                p_align: raw[3] as u64,
+            },
+        )
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_file_header_round_trip(
+            (class, data) in any_layout(),
+            e_type in proptest::sample::select(std::vec![
+                ElfType::Relocatable,
+                ElfType::Executable,
+                ElfType::SharedObject,
+                ElfType::CoreFile,
+            ]),
+            e_machine in proptest::sample::select(std::vec![
+                ElfMachine::Intel80386,
+                ElfMachine::Amd64,
+                ElfMachine::Arm,
+                ElfMachine::Aarch64,
+                ElfMachine::Riscv,
+                ElfMachine::Mips,
+            ]),
+            addrs in proptest::prelude::any::<[u32; 3]>(),
+            e_flags in proptest::prelude::any::<u32>(),
+            sizes in proptest::prelude::any::<[u16; 4]>(),
+            e_phnum in 0..program::PN_XNUM as usize,
+            e_shnum in 1..section::SHN_LORESERVE as usize,
+        ) {
+            let mut ident = [0u8; 16];
+            ident[0x00..0x04].copy_from_slice(b"\x7fELF");
+            ident[0x06] = 1;
+            let hdr = FileHeader {
+                e_ident: ident,
+                e_type,
+                e_machine,
+                e_entry: addrs[0] as u64,
+                e_phoff: addrs[1] as u64,
+                e_shoff: addrs[2] as u64,
+                e_flags,
+                e_ehsize: sizes[0],
+                e_phentsize: sizes[1],
+                e_phnum,
+                e_shentsize: sizes[2],
+                e_shnum,
+                e_shstrndx: sizes[3],
+                ..FileHeader::new()
+            };
+            let mut buf = [0u8; 0x40];
+            hdr.emit(&mut buf, class, data).unwrap();
+
+            // Parsing the header gives back every field
+            let parsed = FileHeader::new().parse(&buf).unwrap();
+            proptest::prop_assert_eq!(parsed.e_class, class);
+            proptest::prop_assert_eq!(parsed.e_data, data);
+            proptest::prop_assert_eq!(parsed.e_type, e_type);
+            proptest::prop_assert_eq!(parsed.e_machine, e_machine);
+            proptest::prop_assert_eq!(
+                (parsed.e_entry, parsed.e_phoff, parsed.e_shoff),
+                (hdr.e_entry, hdr.e_phoff, hdr.e_shoff)
+            );
+            proptest::prop_assert_eq!(parsed.e_flags, e_flags);
+            proptest::prop_assert_eq!(
+                (parsed.e_phnum, parsed.e_shnum, parsed.e_shstrndx),
+                (e_phnum, e_shnum, sizes[3])
+            );
+
+            // And emitting it again gives back the same bytes
+            let mut again = [0u8; 0x40];
+            parsed.emit(&mut again, class, data).unwrap();
+            proptest::prop_assert_eq!(again, buf);
+        }
+
+        #[test]
+        fn prop_header_table_counts(
+            (class, data) in any_layout(),
+            segments in proptest::collection::vec(any_segment(), 0..8),
+            sections in proptest::collection::vec(any_section(), 0..8),
+        ) {
+            let file = synthetic_elf(class, data, &segments, &sections, 0x800);
+            let e = Elf::new(&file).parse().unwrap();
+            proptest::prop_assert_eq!(e.file_header.e_phnum, segments.len());
+            proptest::prop_assert_eq!(e.file_header.e_shnum, sections.len());
+            proptest::prop_assert_eq!(
+                e.program_iter().count(),
+                e.file_header.e_phnum
+            );
+            proptest::prop_assert_eq!(
+                e.section_iter().count(),
+                e.file_header.e_shnum
+            );
+
+            // The file ranges are read back as they were written
+            for (ph, expected) in e.program_iter().zip(&segments) {
+                proptest::prop_assert_eq!(
+                    (ph.p_offset, ph.p_filesz),
+                    (expected.p_offset, expected.p_filesz)
+                );
+            }
+            for (sh, expected) in e.section_iter().zip(&sections) {
+                proptest::prop_assert_eq!(
+                    (sh.sh_offset, sh.sh_size),
+                    (expected.sh_offset, expected.sh_size)
+                );
+            }
+        }
+
+        #[test]
+        fn prop_get_section_in_bounds(
+            (class, data) in any_layout(),
+            sections in proptest::collection::vec(any_section(), 1..8),
+            tail in 0usize..0x2000,
+        ) {
+            let file = synthetic_elf(class, data, &[], &sections, tail);
+            let e = Elf::new(&file).parse().unwrap();
+            for sh in e.section_iter() {
+                match e.get_section(sh) {
+                    Ok(bytes) => {
+                        let len = bytes.len() as u64;
+                        proptest::prop_assert_eq!(len, sh.sh_size)
+                    }
+                    Err(err) => proptest::prop_assert!(
+                        matches!(err, Error::UnreadableSection)
+                    ),
+                }
+            }
+        }
+
+        #[test]
+        fn prop_section_flags_bits(bits in proptest::prelude::any::<u64>()) {
+            use section::{SHF_ALLOC, SHF_COMPRESSED, SHF_EXECINSTR};
+            use section::{SHF_GROUP, SHF_INFO_LINK, SHF_LINK_ORDER};
+            use section::{SHF_MERGE, SHF_OS_NONCONFORMING, SHF_STRINGS};
+            use section::{SHF_TLS, SHF_WRITE};
+            let flags = SectionFlags::from_bits(bits);
+            proptest::prop_assert_eq!(flags.bits(), bits);
+            for (set, mask) in [
+                (flags.is_write(), SHF_WRITE),
+                (flags.is_alloc(), SHF_ALLOC),
+                (flags.is_exec(), SHF_EXECINSTR),
+                (flags.is_merge(), SHF_MERGE),
+                (flags.is_strings(), SHF_STRINGS),
+                (flags.is_info_link(), SHF_INFO_LINK),
+                (flags.is_link_order(), SHF_LINK_ORDER),
+                (flags.is_os_nonconforming(), SHF_OS_NONCONFORMING),
+                (flags.is_group(), SHF_GROUP),
+                (flags.is_tls(), SHF_TLS),
+                (flags.is_compressed(), SHF_COMPRESSED),
+            ] {
+                proptest::prop_assert_eq!(set, bits & mask as u64 != 0);
+                let mask = SectionFlags::from_bits(mask as u64);
+                proptest::prop_assert_eq!(set, flags.contains(mask));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_elf_be32() {
+        let file = std::fs::read("./tests/elf_be32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let hdr = &e.file_header;
+        assert_eq!(hdr.e_data, ElfData::ElfData2Msb);
+        assert_eq!(hdr.e_class, ElfClass::Class32);
+        assert_eq!(hdr.e_machine, ElfMachine::PowerPc);
+        assert_eq!(hdr.e_type, ElfType::Executable);
+        assert_eq!(hdr.e_entry, 0x10010118);
+        assert_eq!((hdr.e_phnum, hdr.e_shnum), (5, 11));
+
+        let types: std::vec::Vec<_> =
+            e.program_iter().map(|ph| ph.p_type).collect();
+        assert_eq!(types, [
+            ProgramType::PtPhdr,
+            ProgramType::PtLoad,
+            ProgramType::PtLoad,
+            ProgramType::PtLoad,
+            ProgramType::PtGnuStack,
+        ]);
+        let data = e.program_header_at_index(3).unwrap();
+        assert_eq!((data.p_filesz, data.p_memsz), (0x04, 0x08));
+
+        let names: std::vec::Vec<_> = e
+            .section_iter()
+            .filter_map(|sh| e.section_name(sh))
+            .collect();
+        assert_eq!(names, [
+            "", ".rodata", ".eh_frame", ".text", ".got2", ".data", ".bss",
+            ".comment", ".symtab", ".shstrtab", ".strtab",
+        ]);
+        let symtab = e.find_section(".symtab").unwrap();
+        assert_eq!(symtab.sh_type, SectionType::ShtSymTab);
+        assert_eq!((symtab.sh_link, symtab.sh_info), (10, 2));
+
+        // The data is kept in the file byte order
+        assert_eq!(e.data_data().unwrap(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(e.rodata_data().unwrap(), [1, 2, 3, 4]);
+        assert!(e.bss_data().unwrap().is_empty());
+
+        let counter = e.find_symbol("COUNTER").unwrap();
+        assert_eq!((counter.st_value, counter.st_size), (0x10020120, 4));
+        assert!(counter.is_object() && counter.is_global());
+        let helper = e.find_symbol("helper").unwrap();
+        assert_eq!(helper.st_value, 0x1001011c);
+        assert!(helper.is_function());
+        let text = e.entry_point_section().unwrap();
+        assert_eq!(e.section_name(text), Some(".text"));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn parse_elf_be64() {
+        let file = std::fs::read("./tests/elf_be64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        let hdr = &e.file_header;
+        assert_eq!(hdr.e_data, ElfData::ElfData2Msb);
+        assert_eq!(hdr.e_class, ElfClass::Class64);
+        assert_eq!(hdr.e_machine, ElfMachine::PowerPc64);
+        assert_eq!(hdr.e_type, ElfType::Executable);
+        assert_eq!(hdr.e_entry, 0x10030240);
+        assert_eq!((hdr.e_phnum, hdr.e_shnum), (7, 14));
+
+        let types: std::vec::Vec<_> =
+            e.program_iter().map(|ph| ph.p_type).collect();
+        assert_eq!(types, [
+            ProgramType::PtPhdr,
+            ProgramType::PtLoad,
+            ProgramType::PtLoad,
+            ProgramType::PtLoad,
+            ProgramType::PtLoad,
+            ProgramType::PtGnuRelro,
+            ProgramType::PtGnuStack,
+        ]);
+        assert!(e.has_relro() && e.has_nx_stack());
+
+        let relro = e.iter_gnu_relro_segments().next().unwrap();
+        let names: std::vec::Vec<_> = e
+            .sections_in_segment_vaddr(&relro)
+            .filter_map(|sh| e.section_name(sh))
+            .collect();
+        assert_eq!(names, [".got", ".relro_padding"]);
+
+        assert_eq!(e.data_data().unwrap(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(e.rodata_data().unwrap(), [1, 2, 3, 4]);
+        assert_eq!(e.bss_section().unwrap().sh_addr, 0x10030278);
+
+        // The entry point is the function descriptor in `.opd`
+        let opd = e.entry_point_section().unwrap();
+        assert_eq!(e.section_name(opd), Some(".opd"));
+        let start = e.find_symbol("_start").unwrap();
+        assert_eq!((start.st_value, start.st_size), (0x10030240, 16));
+        let toc = e.find_symbol(".TOC.").unwrap();
+        assert!(toc.is_local());
+        assert_eq!(toc.visibility(), SymVisibility::Hidden);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn byte_order_and_class_variants() {
+        for (path, class, data) in [
+            ("./tests/elf_test32", ElfClass::Class32, ElfData::ElfData2Lsb),
+            ("./tests/elf_test64", ElfClass::Class64, ElfData::ElfData2Lsb),
+            ("./tests/elf_be32", ElfClass::Class32, ElfData::ElfData2Msb),
+            ("./tests/elf_be64", ElfClass::Class64, ElfData::ElfData2Msb),
+        ] {
+            let file = std::fs::read(path)
+                .expect("no file was found in the test location");
+            let e = Elf::new(file.as_slice()).parse().unwrap();
+            assert_eq!(e.file_header.e_class, class);
+            assert_eq!(e.file_header.e_data, data);
+            assert!(e.validate().is_ok());
+
+            // The header tables are read whole in every layout
+            assert_eq!(e.section_iter().count(), e.file_header.e_shnum);
+            assert_eq!(e.program_iter().count(), e.file_header.e_phnum);
+            assert!(e.section_iter().all(|sh| e.section_name(sh).is_some()));
+            assert!(e.shtstrtab.is_some());
+
+            // Every section is found again by its name
+            for sh in e.section_iter().skip(1) {
+                let name = e.section_name(sh).unwrap();
+                assert_eq!(e.find_section(name), Some(sh));
+            }
+
+            let text = e.text_section().unwrap();
+            assert_eq!(text.sh_type, SectionType::ShtProgBits);
+            assert!(text.sh_flags.is_alloc() && text.sh_flags.is_exec());
+            let symtab = e.find_section_by_type(SectionType::ShtSymTab);
+            let symtab = symtab.unwrap();
+            let strtab = e.linked_section(&symtab).unwrap();
+            assert_eq!(e.section_name(strtab), Some(".strtab"));
+            let count = symtab.sh_size / symtab.sh_entsize;
+            assert_eq!(e.symtab_iter(symtab).count() as u64, count);
+
+            // The data sections are read whole and `.bss` takes no space
+            let data = e.data_section().unwrap();
+            assert_eq!(e.data_data().unwrap().len() as u64, data.sh_size);
+            if let Some(rodata) = e.rodata_section() {
+                let size = e.rodata_data().unwrap().len() as u64;
+                assert_eq!(size, rodata.sh_size);
+            }
+            if let Some(bss) = e.bss_section() {
+                assert_eq!(bss.sh_type, SectionType::ShtNoBits);
+                assert!(e.bss_data().unwrap().is_empty());
+            }
+
+            // The entry point is the `_start` symbol of a loaded segment
+            let entry = e.file_header.e_entry;
+            assert!(e.entry_point_segment().is_some());
+            assert!(e.entry_point_section().is_some());
+            assert_eq!(e.find_symbol("_start").unwrap().st_value, entry);
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn gnu_version_for_symbol64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+
+        // Needed versions come from the `ShtGnuVerNeed` section
+        let versions: std::vec::Vec<_> =
+            (0..15).map(|ndx| e.gnu_version_for_symbol(ndx)).collect();
+        assert_eq!(versions[2], Some("GLIBC_2.2.5"));
+        assert_eq!(versions[3], Some("GLIBC_2.3"));
+
+        // Defined versions come from the `ShtGnuVerDef` section
+        assert!(versions[7..].iter().all(|ver| *ver == Some("VERS_1.0")));
+
+        // Local and unversioned symbols have no version name
+        assert_eq!(versions[0], None);
+        assert_eq!(versions[1], None);
+        assert_eq!(e.gnu_version_for_symbol(15), None);
+
+        // Files without symbol versions have no version names
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+        assert_eq!(e.gnu_version_for_symbol(0), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn resolve_symbol64() {
+        let file = std::fs::read("./tests/elf_test_so64")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+
+        // Defined dynamic symbols have a section and a defined version
+        let sym = e.resolve_symbol("exported_func").unwrap();
+        assert_eq!(sym.name, "exported_func");
+        assert_eq!((sym.entry.st_value, sym.entry.st_size), (0x111f, 27));
+        assert_eq!(sym.entry.st_bind, SymBinding::Global);
+        assert_eq!(e.section_name(sym.section.unwrap()), Some(".text"));
+        assert_eq!(sym.version, Some("VERS_1.0"));
+        let sym = e.resolve_symbol("protected_func").unwrap();
+        assert_eq!(sym.entry.visibility(), SymVisibility::Protected);
+
+        // Undefined symbols have no section but a needed version
+        let sym = e.resolve_symbol("puts").unwrap();
+        assert_eq!(sym.section, None);
+        assert_eq!(sym.version, Some("GLIBC_2.2.5"));
+
+        // Absolute symbols have no section either
+        let sym = e.resolve_symbol("VERS_1.0").unwrap();
+        assert_eq!((sym.section, sym.version), (None, Some("VERS_1.0")));
+        assert!(e.resolve_symbol("missing_func").is_none());
+    }
+
+    #[test]
+    fn resolve_symbol32() {
+        let file = std::fs::read("./tests/elf_test32")
+            .expect("no file was found in the test location");
+        let e = Elf::new(file.as_slice()).parse().unwrap();
+
+        // Static symbols are found in the symbol table without a version
+        let sym = e.resolve_symbol("_start").unwrap();
+        assert_eq!(sym.entry.st_value, e.file_header.e_entry);
+        assert_eq!(e.section_name(sym.section.unwrap()), Some(".text"));
+        assert_eq!(sym.version, None);
     }
 }
@@ -0,0 +1,420 @@
+use crate::utils::{align_up, saturating_usize, Integer};
+use crate::file::{ElfClass, ElfData};
+
+/// Owner name of the GNU notes
+pub const ELF_NOTE_GNU: &[u8] = b"GNU\0";
+
+/// GNU ABI tag note type
+pub const NT_GNU_ABI_TAG: u32 = 1;
+/// GNU unique build ID note type
+pub const NT_GNU_BUILD_ID: u32 = 3;
+/// GNU program property note type
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+/// Owner name of the process state notes of the core files
+pub const ELF_NOTE_CORE: &[u8] = b"CORE\0";
+
+/// Core file note with the process status and the registers
+pub const NT_PRSTATUS: u32 = 1;
+/// Core file note with the floating point registers
+pub const NT_PRFPREG: u32 = 2;
+/// Core file note with the process information
+pub const NT_PRPSINFO: u32 = 3;
+/// Core file note with the auxiliary vector
+pub const NT_AUXV: u32 = 6;
+/// Core file note with the signal information
+pub const NT_SIGINFO: u32 = 0x53494749;
+/// Core file note with the files mapped in the process
+pub const NT_FILE: u32 = 0x46494c45;
+
+/// Build attribute note applying to the code from its address range up to
+/// the next open note
+pub const NT_GNU_BUILD_ATTRIBUTE_OPEN: u32 = 0x100;
+/// Build attribute note applying to a single function
+pub const NT_GNU_BUILD_ATTRIBUTE_FUNC: u32 = 0x101;
+
+/// Program property with the stack size needed by the program
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+/// Program property disabling copy relocations on protected symbols
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+/// Program property with the features needed by the program
+pub const GNU_PROPERTY_1_NEEDED: u32 = 0xb0008000;
+/// x86 control-flow enforcement features which all objects support
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+/// x86 ISA levels needed by the program
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+/// x86 features used by the program
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xc0010001;
+/// x86 ISA levels used by the program
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+/// AArch64 branch protection features which all objects support
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+
+/// Indirect branch tracking bit of `GNU_PROPERTY_X86_FEATURE_1_AND`
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+/// Shadow stack bit of `GNU_PROPERTY_X86_FEATURE_1_AND`
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+/// Branch target identification bit of `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+/// Pointer authentication bit of `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+
+/// Note entry stored in the `ShtNotes` sections and `PtNote` segments
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NoteEnt<'a> {
+    /// Note type which is interpreted according to the owner `name`
+    pub n_type: u32,
+    /// Owner of the note including the null terminator, e.g. `b"GNU\0"`
+    pub name: &'a [u8],
+    /// Note descriptor
+    pub desc: &'a [u8],
+}
+
+/// GNU property stored in the descriptor of a `NT_GNU_PROPERTY_TYPE_0` note
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GnuProperty<'a> {
+    /// Property type like `GNU_PROPERTY_X86_FEATURE_1_AND`
+    pub pr_type: u32,
+    /// Property data which is interpreted according to the `pr_type`
+    pub pr_data: &'a [u8],
+}
+
+/// CoreNoteType specifies the kind of a `CORE` note of a core file
+/// The descriptor layouts are given for Linux on x86-64
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CoreNoteType {
+    /// `NT_PRSTATUS` 336 bytes `elf_prstatus` of a thread with the signal
+    /// at 0x0c, the pid at 0x20 and the 27 `user_regs_struct` registers
+    /// at 0x70
+    Prstatus,
+    /// `NT_PRPSINFO` 136 bytes `elf_prpsinfo` with the process state at
+    /// 0x00, the pid at 0x18, the 16 bytes command name at 0x28 and the 80
+    /// bytes arguments at 0x38
+    Prpsinfo,
+    /// `NT_SIGINFO` 128 bytes `siginfo_t` of the signal which killed the
+    /// process with the signal number at 0x00 and the code at 0x08
+    Siginfo,
+    /// `NT_AUXV` auxiliary vector as pairs of 8 bytes type and value ended
+    /// by `AT_NULL`
+    Auxv,
+    /// `NT_FILE` count and page size followed by the start, end and page
+    /// offset of each mapping then their null terminated file names
+    File,
+    /// Any other note type including the notes of other owners
+    Other(u32),
+}
+
+/// Note of a core file classified by its type
+/// The `desc` bytes of the wrapped note are the raw process state
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CoreNote<'a> {
+    /// Kind of the note
+    pub note_type: CoreNoteType,
+    /// Wrapped note entry
+    pub note: NoteEnt<'a>,
+}
+
+/// GnuBuildAttrName specifies the attribute of a GNU build attribute note
+/// The known attributes are stored as a single byte code in the note name
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnuBuildAttrName<'a> {
+    /// Version of the build attribute specification and of the producer
+    Version,
+    /// `-fstack-protector` level
+    StackProt,
+    /// Whether the program is linked with relro
+    Relro,
+    /// Stack size of the function
+    StackSize,
+    /// Name and version of the compiler
+    Tool,
+    /// ABI of the code
+    Abi,
+    /// `-fpic` or `-fpie` level
+    Pic,
+    /// Whether `-fshort-enums` was used
+    ShortEnum,
+    /// Attribute named by a string like `GOW` or `FORTIFY`
+    Other(&'a str),
+}
+
+/// GnuBuildAttrValue specifies the value of a GNU build attribute note
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnuBuildAttrValue<'a> {
+    /// Numeric value stored in little endian
+    Numeric(u64),
+    /// String value
+    String(&'a str),
+    /// Boolean value
+    Bool(bool),
+}
+
+/// GNU build attribute stored in the `.gnu.build.attributes` section
+/// The attribute and its value are encoded in the note name which starts
+/// with `GA` followed by the value type, the attribute and the value
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GnuBuildAttribute<'a> {
+    /// Note type which is `NT_GNU_BUILD_ATTRIBUTE_OPEN` or
+    /// `NT_GNU_BUILD_ATTRIBUTE_FUNC`
+    pub n_type: u32,
+    /// Attribute of the note
+    pub name: GnuBuildAttrName<'a>,
+    /// Value of the attribute
+    pub value: GnuBuildAttrValue<'a>,
+    /// Start and end addresses the note applies to
+    /// Notes without a range apply to the range of the previous note
+    pub range: Option<(u64, u64)>,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `gnu_build_attributes()` function to be called
+/// on the elf struct
+/// Notes which are not valid build attributes are skipped
+#[derive(Debug, Clone, Copy)]
+pub struct GnuBuildAttrIterator<'a> {
+    /// Notes of the section
+    notes: NoteIterator<'a>,
+    /// Elf class used for the address size of the range
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `note_iter()` or `note_iter_from_segment()`
+/// functions to be called on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct NoteIterator<'a> {
+    /// Offset of the next note in the elf file
+    offset: usize,
+    /// End offset of the notes in the elf file
+    end: usize,
+    /// Alignment of the name and descriptor fields which is 4 or 8
+    align: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `gnu_properties()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct GnuPropertyIterator<'a> {
+    /// Offset of the next property in the note descriptor
+    offset: usize,
+    /// Alignment of the properties which is 4 or 8
+    align: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the note descriptor
+    desc: &'a [u8],
+}
+
+impl<'a> Iterator for NoteIterator<'a> {
+    type Item = NoteEnt<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // If there is no room for another note header abort the iterator
+        let header = self.offset.checked_add(0x0c)?;
+        if header > self.end {
+            return None;
+        }
+
+        // Parse the note header
+        let note = self.elf.get(self.offset..self.end)?;
+        let namesz = u32::endian_parse(0x00..0x04, note, &self.data).ok()?;
+        let descsz = u32::endian_parse(0x04..0x08, note, &self.data).ok()?;
+        let n_type = u32::endian_parse(0x08..0x0c, note, &self.data).ok()?;
+
+        // Get the owner name which directly follows the header
+        let name_end = 0x0c_usize.checked_add(namesz as usize)?;
+        let name = note.get(0x0c..name_end)?;
+
+        // Get the descriptor which starts at the next aligned offset
+        let desc_start = align_up(name_end, self.align)?;
+        let desc_end = desc_start.checked_add(descsz as usize)?;
+        let desc = note.get(desc_start..desc_end)?;
+
+        // Calculate the next offset for the next note
+        let next = align_up(desc_end, self.align)?;
+        self.offset = self.offset.checked_add(next)?;
+
+        Some(NoteEnt { n_type, name, desc })
+    }
+}
+
+impl<'a> NoteIterator<'a> {
+    pub fn new(
+        offset: u64,
+        size: u64,
+        align: u64,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        NoteIterator {
+            offset: saturating_usize(offset),
+            end: saturating_usize(offset.saturating_add(size)),
+            // Notes are 4 byte aligned unless the container asks for 8
+            align: if align == 8 { 8 } else { 4 },
+            data,
+            elf,
+        }
+    }
+}
+
+impl<'a> From<NoteEnt<'a>> for CoreNote<'a> {
+    fn from(note: NoteEnt<'a>) -> Self {
+        // Only the `CORE` owner defines the process state note types
+        let note_type = if note.name != ELF_NOTE_CORE {
+            CoreNoteType::Other(note.n_type)
+        } else {
+            match note.n_type {
+                NT_PRSTATUS => CoreNoteType::Prstatus,
+                NT_PRPSINFO => CoreNoteType::Prpsinfo,
+                NT_SIGINFO  => CoreNoteType::Siginfo,
+                NT_AUXV     => CoreNoteType::Auxv,
+                NT_FILE     => CoreNoteType::File,
+                n_type      => CoreNoteType::Other(n_type),
+            }
+        };
+        CoreNote { note_type, note }
+    }
+}
+
+impl<'a> GnuBuildAttribute<'a> {
+    /// Decode the build attribute of the `note` or `None` if the note is
+    /// not a valid build attribute
+    #[must_use]
+    pub fn parse(
+        note: NoteEnt<'a>,
+        class: ElfClass,
+        data: ElfData,
+    ) -> Option<Self> {
+        if note.n_type != NT_GNU_BUILD_ATTRIBUTE_OPEN
+            && note.n_type != NT_GNU_BUILD_ATTRIBUTE_FUNC
+        {
+            return None;
+        }
+
+        // The null terminated name starts with `GA` and the value type
+        let name = note.name.strip_prefix(b"GA")?.strip_suffix(b"\0")?;
+        let (&ty, rest) = name.split_first()?;
+
+        // Known attributes are a single byte code otherwise the attribute
+        // is a null terminated string
+        let (&code, after) = rest.split_first()?;
+        let (attr, rest) = match code {
+            1 => (GnuBuildAttrName::Version, after),
+            2 => (GnuBuildAttrName::StackProt, after),
+            3 => (GnuBuildAttrName::Relro, after),
+            4 => (GnuBuildAttrName::StackSize, after),
+            5 => (GnuBuildAttrName::Tool, after),
+            6 => (GnuBuildAttrName::Abi, after),
+            7 => (GnuBuildAttrName::Pic, after),
+            8 => (GnuBuildAttrName::ShortEnum, after),
+            _ => {
+                let len = rest.iter().position(|&byte| byte == b'\0');
+                let len = len.unwrap_or(rest.len());
+                let attr = core::str::from_utf8(&rest[..len]).ok()?;
+                let rest = rest.get(len + 1..).unwrap_or(&[]);
+                (GnuBuildAttrName::Other(attr), rest)
+            }
+        };
+
+        let value = match ty {
+            b'*' => {
+                // Numbers are stored without their leading zero bytes
+                if rest.len() > 8 {
+                    return None;
+                }
+                let value = rest
+                    .iter()
+                    .rev()
+                    .fold(0u64, |value, &byte| (value << 8) | byte as u64);
+                GnuBuildAttrValue::Numeric(value)
+            }
+            b'$' => {
+                GnuBuildAttrValue::String(core::str::from_utf8(rest).ok()?)
+            }
+            b'+' => GnuBuildAttrValue::Bool(true),
+            b'!' => GnuBuildAttrValue::Bool(false),
+            _ => return None,
+        };
+
+        // The descriptor is empty or holds the start and end addresses
+        let range = if note.desc.is_empty() {
+            None
+        } else if class == ElfClass::Class32 && note.desc.len() == 0x08 {
+            let start = u32::endian_parse(0x00..0x04, note.desc, &data).ok()?;
+            let end = u32::endian_parse(0x04..0x08, note.desc, &data).ok()?;
+            Some((start as u64, end as u64))
+        } else if note.desc.len() == 0x10 {
+            let start = u64::endian_parse(0x00..0x08, note.desc, &data).ok()?;
+            let end = u64::endian_parse(0x08..0x10, note.desc, &data).ok()?;
+            Some((start, end))
+        } else {
+            return None;
+        };
+
+        Some(GnuBuildAttribute {
+            n_type: note.n_type,
+            name: attr,
+            value,
+            range,
+        })
+    }
+}
+
+impl<'a> Iterator for GnuBuildAttrIterator<'a> {
+    type Item = GnuBuildAttribute<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (class, data) = (self.class, self.data);
+        self.notes
+            .find_map(|note| GnuBuildAttribute::parse(note, class, data))
+    }
+}
+
+impl<'a> GnuBuildAttrIterator<'a> {
+    pub fn new(
+        notes: NoteIterator<'a>,
+        class: ElfClass,
+        data: ElfData,
+    ) -> Self {
+        GnuBuildAttrIterator { notes, class, data }
+    }
+}
+
+impl<'a> Iterator for GnuPropertyIterator<'a> {
+    type Item = GnuProperty<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Parse the property header
+        let property = self.desc.get(self.offset..)?;
+        let pr_type = u32::endian_parse(0x00..0x04, property, &self.data)
+            .ok()?;
+        let datasz = u32::endian_parse(0x04..0x08, property, &self.data)
+            .ok()?;
+
+        // Get the property data which directly follows the header
+        let data_end = 0x08_usize.checked_add(datasz as usize)?;
+        let pr_data = property.get(0x08..data_end)?;
+
+        // Calculate the next offset for the next property
+        let next = align_up(data_end, self.align)?;
+        self.offset = self.offset.checked_add(next)?;
+
+        Some(GnuProperty { pr_type, pr_data })
+    }
+}
+
+impl<'a> GnuPropertyIterator<'a> {
+    pub fn new(desc: &'a [u8], class: ElfClass, data: ElfData) -> Self {
+        GnuPropertyIterator {
+            offset: 0,
+            // Properties are aligned to the size of an address
+            align: if class == ElfClass::Class32 { 4 } else { 8 },
+            data,
+            desc,
+        }
+    }
+}
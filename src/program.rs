@@ -1,36 +1,43 @@
 use crate::Result;
-use crate::utils::Integer;
+use crate::Error;
+use crate::utils::{checked_table_len, contains, narrow, overlaps};
+use crate::utils::{saturating_usize, Integer};
 use crate::file::{ElfData, ElfClass};
 
 pub const PF_X: usize = 1 << 0;
 pub const PF_W: usize = 1 << 1;
 pub const PF_R: usize = 1 << 2;
 
+/// Program header count is stored in the `sh_info` of the first section
+pub const PN_XNUM: u16 = 0xffff;
+
 /// ProgramHeader stores information regarding to how the image sections
 /// should be laid out in the system memory
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramHeader {
     /// Identifies the type of the segment
     pub p_type: ProgramType,
     /// Segment flags
     pub p_flags: Perm,
     /// Offset of the segment in the file
-    pub p_offset: usize,
+    pub p_offset: u64,
     /// Virtual address of the segment in memory
-    pub p_vaddr: usize,
+    pub p_vaddr: u64,
     /// Reserved for the physical address in the memory
-    pub p_paddr: usize,
+    pub p_paddr: u64,
     /// Size of the segment in bytes
-    pub p_filesz: usize,
+    pub p_filesz: u64,
     /// Size of the segment mapped in memory in bytes
-    pub p_memsz: usize,
+    pub p_memsz: u64,
     /// Specifies alignment and should be integral power of 2 (1 and 0 are no
     /// alignment)
-    pub p_align: usize,
+    pub p_align: u64,
 }
 
 /// Enum to identify the program header type
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProgramType {
     None,
     /// Program header entry is unused
@@ -58,18 +65,33 @@ pub enum ProgramType {
     /// GNU property
     PtGnuProperty,
     /// OS specific segment
-    PtOs,
+    PtOs(u32),
     /// Processor specific segment
-    PtProc,
+    PtProc(u32),
+    /// Segment type without a name
+    Unknown(u32),
 }
 
 /// struct to represent RWX perms on the program header
 /// The three booleans represented in the struct are Read, Write, Execute
 /// in order
 /// It is best that associated functions be used when using this struct
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Perm(pub bool, pub bool, pub bool);
 
+/// Thread-local storage image described by the `PtTls` segment
+/// Each thread gets a copy of `data` followed by `zero_size` zero bytes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TlsTemplate<'a> {
+    /// Initialization data of the `.tdata` sections
+    pub data: &'a [u8],
+    /// Size of the zero initialized `.tbss` part following the data
+    pub zero_size: usize,
+    /// Alignment of the thread-local storage block
+    pub align: usize,
+}
+
 /// Helper type to implement the iterator type on
 /// The best is for the `program_iter()` function to be called
 /// on the elf struct
@@ -83,7 +105,7 @@ pub struct ProgramIterator<'a> {
     phentsize: u16,
     /// Number of program header entries also used as
     /// the index of the iteration
-    phnum: u16,
+    phnum: usize,
     /// Elf class used for parsing
     class: ElfClass,
     /// Elf endianness used for parsing
@@ -92,6 +114,77 @@ pub struct ProgramIterator<'a> {
     elf: &'a [u8],
 }
 
+impl From<u32> for ProgramType {
+    fn from(value: u32) -> Self {
+        match value {
+            0x00000000 => ProgramType::PtNull,
+            0x00000001 => ProgramType::PtLoad,
+            0x00000002 => ProgramType::PtDynamic,
+            0x00000003 => ProgramType::PtInterp,
+            0x00000004 => ProgramType::PtNote,
+            0x00000005 => ProgramType::PtShlib,
+            0x00000006 => ProgramType::PtPhdr,
+            0x00000007 => ProgramType::PtTls,
+            0x6474e550 => ProgramType::PtGnuEhFrame,
+            0x6474e551 => ProgramType::PtGnuStack,
+            0x6474e552 => ProgramType::PtGnuRelro,
+            0x6474e553 => ProgramType::PtGnuProperty,
+            0x60000000..=0x6fffffff => ProgramType::PtOs(value),
+            0x70000000..=0x7fffffff => ProgramType::PtProc(value),
+            _ => ProgramType::Unknown(value),
+        }
+    }
+}
+
+impl ProgramType {
+    /// Returns the raw `p_type` value of the program type
+    /// Range and unknown variants return the value they hold and `None`
+    /// returns zero as it has no value
+    pub fn raw_value(self) -> u32 {
+        match self {
+            ProgramType::None          => 0x00000000,
+            ProgramType::PtNull        => 0x00000000,
+            ProgramType::PtLoad        => 0x00000001,
+            ProgramType::PtDynamic     => 0x00000002,
+            ProgramType::PtInterp      => 0x00000003,
+            ProgramType::PtNote        => 0x00000004,
+            ProgramType::PtShlib       => 0x00000005,
+            ProgramType::PtPhdr        => 0x00000006,
+            ProgramType::PtTls         => 0x00000007,
+            ProgramType::PtGnuEhFrame  => 0x6474e550,
+            ProgramType::PtGnuStack    => 0x6474e551,
+            ProgramType::PtGnuRelro    => 0x6474e552,
+            ProgramType::PtGnuProperty => 0x6474e553,
+            ProgramType::PtOs(value)   => value,
+            ProgramType::PtProc(value) => value,
+            ProgramType::Unknown(value) => value,
+        }
+    }
+}
+
+impl core::fmt::Display for ProgramType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ProgramType::None          => "UNKNOWN",
+            ProgramType::PtNull        => "NULL",
+            ProgramType::PtLoad        => "LOAD",
+            ProgramType::PtDynamic     => "DYNAMIC",
+            ProgramType::PtInterp      => "INTERP",
+            ProgramType::PtNote        => "NOTE",
+            ProgramType::PtShlib       => "SHLIB",
+            ProgramType::PtPhdr        => "PHDR",
+            ProgramType::PtTls         => "TLS",
+            ProgramType::PtGnuEhFrame  => "GNU_EH_FRAME",
+            ProgramType::PtGnuStack    => "GNU_STACK",
+            ProgramType::PtGnuRelro    => "GNU_RELRO",
+            ProgramType::PtGnuProperty => "GNU_PROPERTY",
+            ProgramType::PtOs(_)       => "LOOS+",
+            ProgramType::PtProc(_)     => "LOPROC+",
+            ProgramType::Unknown(_)    => "UNKNOWN",
+        })
+    }
+}
+
 impl Default for ProgramHeader {
     fn default() -> Self {
         Self::new()
@@ -114,6 +207,7 @@ impl ProgramHeader {
     }
 
     /// Parse the `ProgramHeader` and populate the fields
+    #[inline]
     pub fn parse(
         mut self,
         elf: &[u8],
@@ -121,54 +215,38 @@ impl ProgramHeader {
         data: ElfData,
     ) -> Result<Self> {
         // Get the segment type
-        self.p_type = match elf.get(0x00..0x04) {
-            Some(&[0x00, 0x00, 0x00, 0x00]) => ProgramType::PtNull,
-            Some(&[0x01, 0x00, 0x00, 0x00]) => ProgramType::PtLoad,
-            Some(&[0x02, 0x00, 0x00, 0x00]) => ProgramType::PtDynamic,
-            Some(&[0x03, 0x00, 0x00, 0x00]) => ProgramType::PtInterp,
-            Some(&[0x04, 0x00, 0x00, 0x00]) => ProgramType::PtNote,
-            Some(&[0x05, 0x00, 0x00, 0x00]) => ProgramType::PtShlib,
-            Some(&[0x06, 0x00, 0x00, 0x00]) => ProgramType::PtPhdr,
-            Some(&[0x50, 0xe5, 0x74, 0x64]) => ProgramType::PtGnuEhFrame,
-            Some(&[0x51, 0xe5, 0x74, 0x64]) => ProgramType::PtGnuStack,
-            Some(&[0x52, 0xe5, 0x74, 0x64]) => ProgramType::PtGnuRelro,
-            Some(&[0x53, 0xe5, 0x74, 0x64]) => ProgramType::PtGnuProperty,
-            Some(&[_, _, _, 0x60..=0x6f]) => ProgramType::PtOs,
-            Some(&[_, _, _, 0x70..=0x7f]) => ProgramType::PtProc,
-            _ => ProgramType::None,
-        };
+        self.p_type =
+            ProgramType::from(u32::endian_parse(0x00..0x04, elf, &data)?);
 
         // Branch and parse according to the elf architecture class
         if class == ElfClass::Class32 {
             // Get the program offset of the segment
-            self.p_offset =
-                u32::endian_parse(0x04..0x08, elf, &data)? as usize;
+            self.p_offset = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
 
             // Get the program offset of the segment in the virtual memory
-            self.p_vaddr = u32::endian_parse(0x08..0x0c, elf, &data)? as usize;
+            self.p_vaddr = u32::endian_parse(0x08..0x0c, elf, &data)? as u64;
 
             // Get the program offset of the segment in the physical memory
             // This part is only relevant in systems without which use
             // memory segmentation
-            self.p_paddr = u32::endian_parse(0x0c..0x10, elf, &data)? as usize;
+            self.p_paddr = u32::endian_parse(0x0c..0x10, elf, &data)? as u64;
 
             // Size of the file image segment in bytes
-            self.p_filesz =
-                u32::endian_parse(0x10..0x14, elf, &data)? as usize;
+            self.p_filesz = u32::endian_parse(0x10..0x14, elf, &data)? as u64;
 
             // Size of the segment mapped in the memory in bytes
-            self.p_memsz = u32::endian_parse(0x14..0x18, elf, &data)? as usize;
+            self.p_memsz = u32::endian_parse(0x14..0x18, elf, &data)? as u64;
 
             // Get the memory permissions of the segment
             let flags = u32::endian_parse(0x18..0x1c, elf, &data)? as usize;
-            self.p_flags.2 = flags as usize & PF_X != 0;
-            self.p_flags.1 = flags as usize & PF_W != 0;
-            self.p_flags.0 = flags as usize & PF_R != 0;
+            self.p_flags.2 = flags & PF_X != 0;
+            self.p_flags.1 = flags & PF_W != 0;
+            self.p_flags.0 = flags & PF_R != 0;
 
             // Specifies alignment
             // 0 and 1 specify no alignment otherwise it should be integral
             // power of 2
-            self.p_align = u32::endian_parse(0x1c..0x20, elf, &data)? as usize;
+            self.p_align = u32::endian_parse(0x1c..0x20, elf, &data)? as u64;
         } else if class == ElfClass::Class64 {
             // Get the memory permissions of the segment
             let flags = u32::endian_parse(0x04..0x08, elf, &data)?;
@@ -177,44 +255,123 @@ impl ProgramHeader {
             self.p_flags.0 = flags as usize & PF_R != 0;
 
             // Get the program offset of the segment in the file image
-            self.p_offset = usize::endian_parse(0x08..0x10, elf, &data)?;
+            self.p_offset = u64::endian_parse(0x08..0x10, elf, &data)?;
 
             // Get the program offset of the segment in the virtual memory
-            self.p_vaddr = usize::endian_parse(0x10..0x18, elf, &data)?;
+            self.p_vaddr = u64::endian_parse(0x10..0x18, elf, &data)?;
 
             // Get the program offset of the segment in the physical memory
             // This part is only relevant in systems without which use
             // memory segmentation
-            self.p_paddr = usize::endian_parse(0x18..0x20, elf, &data)?;
+            self.p_paddr = u64::endian_parse(0x18..0x20, elf, &data)?;
 
             // Size of the file image segment in bytes
-            self.p_filesz = usize::endian_parse(0x20..0x28, elf, &data)?;
+            self.p_filesz = u64::endian_parse(0x20..0x28, elf, &data)?;
 
             // Size of the segment mapped in the memory in bytes
-            self.p_memsz = usize::endian_parse(0x28..0x30, elf, &data)?;
+            self.p_memsz = u64::endian_parse(0x28..0x30, elf, &data)?;
 
             // Specifies alignment
             // 0 and 1 specify no alignment otherwise it should be integral
             // power of 2
-            self.p_align = usize::endian_parse(0x30..0x38, elf, &data)?;
+            self.p_align = u64::endian_parse(0x30..0x38, elf, &data)?;
         }
 
         Ok(self)
     }
+
+    /// Write the program header into `buf` with the layout and the byte
+    /// order of the elf class and data encoding
+    /// This is the inverse of `parse`
+    pub fn emit(
+        &self,
+        buf: &mut [u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<()> {
+        self.p_type.raw_value().endian_emit(0x00..0x04, buf, &data)?;
+
+        // Pack the memory permissions of the segment
+        let mut flags = 0;
+        if self.p_flags.0 {
+            flags |= PF_R;
+        }
+        if self.p_flags.1 {
+            flags |= PF_W;
+        }
+        if self.p_flags.2 {
+            flags |= PF_X;
+        }
+        let flags = flags as u32;
+
+        // Branch and emit according to the elf architecture class
+        if class == ElfClass::Class32 {
+            narrow(self.p_offset)?.endian_emit(0x04..0x08, buf, &data)?;
+            narrow(self.p_vaddr)?.endian_emit(0x08..0x0c, buf, &data)?;
+            narrow(self.p_paddr)?.endian_emit(0x0c..0x10, buf, &data)?;
+            narrow(self.p_filesz)?.endian_emit(0x10..0x14, buf, &data)?;
+            narrow(self.p_memsz)?.endian_emit(0x14..0x18, buf, &data)?;
+            flags.endian_emit(0x18..0x1c, buf, &data)?;
+            narrow(self.p_align)?.endian_emit(0x1c..0x20, buf, &data)?;
+        } else if class == ElfClass::Class64 {
+            flags.endian_emit(0x04..0x08, buf, &data)?;
+            self.p_offset.endian_emit(0x08..0x10, buf, &data)?;
+            self.p_vaddr.endian_emit(0x10..0x18, buf, &data)?;
+            self.p_paddr.endian_emit(0x18..0x20, buf, &data)?;
+            self.p_filesz.endian_emit(0x20..0x28, buf, &data)?;
+            self.p_memsz.endian_emit(0x28..0x30, buf, &data)?;
+            self.p_align.endian_emit(0x30..0x38, buf, &data)?;
+        } else {
+            return Err(Error::UnsupportedClass);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the virtual address `addr` is inside the segment
+    /// memory image including its memory only part
+    #[must_use]
+    pub fn contains_vaddr(&self, addr: u64) -> bool {
+        contains(self.p_vaddr, self.p_memsz, addr)
+    }
+
+    /// Returns true if the file `offset` is inside the segment file image
+    #[must_use]
+    pub fn contains_file_offset(&self, offset: u64) -> bool {
+        contains(self.p_offset, self.p_filesz, offset)
+    }
+
+    /// Returns true if the memory images of both segments overlap
+    #[must_use]
+    pub fn overlaps_vaddr(&self, other: &ProgramHeader) -> bool {
+        overlaps(self.p_vaddr, self.p_memsz, other.p_vaddr, other.p_memsz)
+    }
+
+    /// Returns true if the file images of both segments overlap
+    #[must_use]
+    pub fn overlaps_file(&self, other: &ProgramHeader) -> bool {
+        overlaps(self.p_offset, self.p_filesz, other.p_offset, other.p_filesz)
+    }
 }
 
 impl Perm {
     /// Return if the section is readable
+    #[inline]
+    #[must_use]
     pub fn is_read(self) -> bool {
         self.0
     }
 
     /// Return if the section is writable
+    #[inline]
+    #[must_use]
     pub fn is_write(self) -> bool {
         self.1
     }
 
     /// Return if the section is executable
+    #[inline]
+    #[must_use]
     pub fn is_exec(self) -> bool {
         self.2
     }
@@ -222,38 +379,72 @@ impl Perm {
 
 impl<'a> Iterator for ProgramIterator<'a> {
     type Item = ProgramHeader;
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         // If the number of program headers is zero then abort the iterator
         if self.phnum == 0 {
             None
         } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let phnum = core::mem::take(&mut self.phnum);
+
             // Parse the program header into the struct
+            let end = self.offset.checked_add(self.phentsize as usize)?;
             self.program_header = self
                 .program_header
-                .parse(
-                    &self.elf
-                        [self.offset..self.offset + self.phentsize as usize],
-                    self.class,
-                    self.data,
-                )
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
                 .ok()?;
 
             // Calculate the next offset for the next program header
-            self.offset += self.phentsize as usize;
+            self.offset = end;
 
             // Subtract one from the number of the program headers
-            self.phnum -= 1;
+            self.phnum = phnum - 1;
 
             Some(self.program_header)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.phnum, Some(self.phnum))
+    }
+}
+
+impl<'a> ExactSizeIterator for ProgramIterator<'a> {}
+
+impl<'a> DoubleEndedIterator for ProgramIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // If the number of program headers is zero then abort the iterator
+        if self.phnum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration and the length stays exact
+            let phnum = core::mem::take(&mut self.phnum);
+
+            // The last unvisited entry is found from the front offset
+            let start = (phnum - 1)
+                .checked_mul(self.phentsize as usize)
+                .and_then(|skip| self.offset.checked_add(skip))?;
+            let end = start.checked_add(self.phentsize as usize)?;
+            let program_header = ProgramHeader::new()
+                .parse(self.elf.get(start..end)?, self.class, self.data)
+                .ok()?;
+
+            // Subtract one from the number of the program headers
+            self.phnum = phnum - 1;
+
+            Some(program_header)
+        }
+    }
 }
 
 impl<'a> ProgramIterator<'a> {
     pub fn new(
-        e_phoff: usize,
+        e_phoff: u64,
         e_phentsize: u16,
-        e_phnum: u16,
+        e_phnum: usize,
         class: ElfClass,
         data: ElfData,
         elf: &'a [u8],
@@ -261,14 +452,31 @@ impl<'a> ProgramIterator<'a> {
         // Construct a empty program header for the program iterator
         let program = ProgramHeader::new();
 
+        let offset = saturating_usize(e_phoff);
+        let phnum = checked_table_len(
+            elf,
+            offset,
+            e_phentsize as usize,
+            e_phnum,
+            |ent| program.parse(ent, class, data),
+        );
+
         ProgramIterator {
             program_header: program,
-            offset: e_phoff,
+            offset,
             phentsize: e_phentsize,
-            phnum: e_phnum,
+            phnum,
             class,
             data,
             elf,
         }
     }
+
+    /// Returns an iterator over the remaining segments of type `ty`
+    pub fn of_type(
+        self,
+        ty: ProgramType,
+    ) -> impl Iterator<Item = ProgramHeader> + 'a {
+        self.filter(move |segment| segment.p_type == ty)
+    }
 }
@@ -0,0 +1,565 @@
+use crate::Result;
+use crate::utils::{checked_table_len, saturating_usize, Integer};
+use crate::file::{ElfData, ElfClass, ElfMachine};
+use crate::section::SectionHeader;
+
+pub mod aarch64;
+pub mod arm;
+pub mod riscv;
+pub mod x86_64;
+
+/// i386 relocation storing the load address plus the addend in place
+pub const R_386_RELATIVE: u32 = 8;
+
+/// Relocation entry with an explicit addend stored in `ShtRela` sections
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RelaEnt {
+    /// Location to apply the relocation action
+    pub r_offset: u64,
+    /// Symbol table index and the type of the relocation
+    pub r_info: u64,
+    /// Constant addend used to compute the relocated value
+    pub r_addend: i64,
+    /// Elf class used to split the `r_info` field
+    class: ElfClass,
+    /// Elf machine used to interpret the `r_info` type
+    machine: ElfMachine,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `rela_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct RelaIterator<'a> {
+    /// Owned relocation entry struct to address
+    rela: RelaEnt,
+    /// Relocation table offset in the elf file
+    offset: usize,
+    /// Relocation table entry size
+    entsize: usize,
+    /// Number of relocation entries also used as
+    /// the index of the iteration
+    relanum: usize,
+    /// Elf class used for parsing
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Relocation entry without an addend stored in `ShtRel` sections
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RelEnt {
+    /// Location to apply the relocation action
+    pub r_offset: u64,
+    /// Symbol table index and the type of the relocation
+    pub r_info: u64,
+    /// Elf class used to split the `r_info` field
+    class: ElfClass,
+    /// Elf machine used to interpret the `r_info` type
+    machine: ElfMachine,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `rel_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct RelIterator<'a> {
+    /// Owned relocation entry struct to address
+    rel: RelEnt,
+    /// Relocation table offset in the elf file
+    offset: usize,
+    /// Relocation table entry size
+    entsize: usize,
+    /// Number of relocation entries also used as
+    /// the index of the iteration
+    relnum: usize,
+    /// Elf class used for parsing
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Entry of a `ShtRelr` section which is either the address of a relative
+/// relocation or a bitmap of the relocations following the last address
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RelrEntry {
+    /// Even entry holding the address of a relative relocation
+    Address(u64),
+    /// Odd entry where every set bit above the lowest one marks a relative
+    /// relocation after the last address
+    Bitmap(u64),
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `relr_iter()` function to be called
+/// on the elf struct
+/// The iterator decodes the entries and yields the addresses where a
+/// relative relocation must be applied
+#[derive(Debug, Clone, Copy)]
+pub struct RelrIterator<'a> {
+    /// Relocation table offset in the elf file
+    offset: usize,
+    /// Number of entries left to decode
+    relrnum: usize,
+    /// Address the next bitmap entry is relative to
+    base: u64,
+    /// Remaining bits of the bitmap being decoded
+    bitmap: u64,
+    /// Address the bitmap being decoded is relative to
+    bitmap_base: u64,
+    /// Elf class used for parsing
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+impl Default for RelaEnt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelaEnt {
+    /// The default `RelaEnt` constructor
+    pub fn new() -> Self {
+        RelaEnt {
+            r_offset: 0,
+            r_info: 0,
+            r_addend: 0,
+            class: ElfClass::None,
+            machine: ElfMachine::None,
+        }
+    }
+
+    /// Parse the relocation entry and populate the fields
+    pub fn parse(
+        mut self,
+        elf: &[u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<Self> {
+        self.class = class;
+
+        // Branch and parse according to the elf architecture class
+        if class == ElfClass::Class32 {
+            // Get the location of the relocation
+            self.r_offset = u32::endian_parse(0x00..0x04, elf, &data)? as u64;
+
+            // Get the symbol index and the relocation type
+            self.r_info = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
+
+            // Get the signed addend
+            self.r_addend =
+                u32::endian_parse(0x08..0x0c, elf, &data)? as i32 as i64;
+        } else if class == ElfClass::Class64 {
+            // Get the location of the relocation
+            self.r_offset = u64::endian_parse(0x00..0x08, elf, &data)?;
+
+            // Get the symbol index and the relocation type
+            self.r_info = u64::endian_parse(0x08..0x10, elf, &data)?;
+
+            // Get the signed addend
+            self.r_addend = u64::endian_parse(0x10..0x18, elf, &data)? as i64;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the symbol table index of the relocation
+    /// Follows the `ELF32_R_SYM` and `ELF64_R_SYM` macros
+    pub fn r_sym(&self) -> u32 {
+        if self.class == ElfClass::Class32 {
+            (self.r_info >> 8) as u32
+        } else {
+            (self.r_info >> 32) as u32
+        }
+    }
+
+    /// Returns the processor specific relocation type
+    /// Follows the `ELF32_R_TYPE` and `ELF64_R_TYPE` macros
+    pub fn r_type(&self) -> u32 {
+        if self.class == ElfClass::Class32 {
+            (self.r_info & 0xff) as u32
+        } else {
+            (self.r_info & 0xffffffff) as u32
+        }
+    }
+
+    /// Returns the x86-64 relocation type or `None` if the relocation
+    /// does not come from an `Amd64` elf file
+    #[must_use]
+    pub fn r_type_x86_64(&self) -> Option<x86_64::RelocType> {
+        self.typed_r_type(ElfMachine::Amd64)
+    }
+
+    /// Returns the AArch64 relocation type or `None` if the relocation
+    /// does not come from an `Aarch64` elf file
+    #[must_use]
+    pub fn r_type_aarch64(&self) -> Option<aarch64::RelocType> {
+        self.typed_r_type(ElfMachine::Aarch64)
+    }
+
+    /// Returns the ARM relocation type or `None` if the relocation does
+    /// not come from an `Arm` elf file
+    #[must_use]
+    pub fn r_type_arm(&self) -> Option<arm::RelocType> {
+        self.typed_r_type(ElfMachine::Arm)
+    }
+
+    /// Returns the RISC-V relocation type or `None` if the relocation
+    /// does not come from a `Riscv` elf file
+    #[must_use]
+    pub fn r_type_riscv(&self) -> Option<riscv::RelocType> {
+        self.typed_r_type(ElfMachine::Riscv)
+    }
+
+    /// Converts the relocation type when the elf machine is `machine`
+    fn typed_r_type<T: From<u32>>(&self, machine: ElfMachine) -> Option<T> {
+        if self.machine != machine {
+            return None;
+        }
+        Some(T::from(self.r_type()))
+    }
+}
+
+impl<'a> Iterator for RelaIterator<'a> {
+    type Item = RelaEnt;
+    fn next(&mut self) -> Option<Self::Item> {
+        // If the number of relocations is zero then abort the iterator
+        if self.relanum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let relanum = core::mem::take(&mut self.relanum);
+
+            // Parse the relocation entry into the struct
+            let end = self.offset.checked_add(self.entsize)?;
+            self.rela = self
+                .rela
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
+                .ok()?;
+
+            // Calculate the next offset for the next relocation
+            self.offset = end;
+
+            // Subtract one from the number of the relocations
+            self.relanum = relanum - 1;
+
+            Some(self.rela)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.relanum, Some(self.relanum))
+    }
+}
+
+impl<'a> ExactSizeIterator for RelaIterator<'a> {}
+
+impl<'a> RelaIterator<'a> {
+    pub fn new(
+        sh: SectionHeader,
+        class: ElfClass,
+        machine: ElfMachine,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        // Construct a empty relocation for the relocation iterator
+        let mut rela = RelaEnt::new();
+        rela.machine = machine;
+
+        // Sections without a fixed entry size can not be iterated
+        let relanum = sh.sh_size.checked_div(sh.sh_entsize).unwrap_or(0);
+        let offset = saturating_usize(sh.sh_offset);
+        let entsize = saturating_usize(sh.sh_entsize);
+        let relanum = checked_table_len(
+            elf,
+            offset,
+            entsize,
+            saturating_usize(relanum),
+            |ent| rela.parse(ent, class, data),
+        );
+
+        RelaIterator {
+            rela,
+            offset,
+            entsize,
+            relanum,
+            class,
+            data,
+            elf,
+        }
+    }
+}
+
+impl Default for RelEnt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelEnt {
+    /// The default `RelEnt` constructor
+    pub fn new() -> Self {
+        RelEnt {
+            r_offset: 0,
+            r_info: 0,
+            class: ElfClass::None,
+            machine: ElfMachine::None,
+        }
+    }
+
+    /// Parse the relocation entry and populate the fields
+    pub fn parse(
+        mut self,
+        elf: &[u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<Self> {
+        self.class = class;
+
+        // Branch and parse according to the elf architecture class
+        if class == ElfClass::Class32 {
+            // Get the location of the relocation
+            self.r_offset = u32::endian_parse(0x00..0x04, elf, &data)? as u64;
+
+            // Get the symbol index and the relocation type
+            self.r_info = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
+        } else if class == ElfClass::Class64 {
+            // Get the location of the relocation
+            self.r_offset = u64::endian_parse(0x00..0x08, elf, &data)?;
+
+            // Get the symbol index and the relocation type
+            self.r_info = u64::endian_parse(0x08..0x10, elf, &data)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the symbol table index of the relocation
+    /// Follows the `ELF32_R_SYM` and `ELF64_R_SYM` macros
+    pub fn r_sym(&self) -> u32 {
+        if self.class == ElfClass::Class32 {
+            (self.r_info >> 8) as u32
+        } else {
+            (self.r_info >> 32) as u32
+        }
+    }
+
+    /// Returns the processor specific relocation type
+    /// Follows the `ELF32_R_TYPE` and `ELF64_R_TYPE` macros
+    pub fn r_type(&self) -> u32 {
+        if self.class == ElfClass::Class32 {
+            (self.r_info & 0xff) as u32
+        } else {
+            (self.r_info & 0xffffffff) as u32
+        }
+    }
+
+    /// Returns the x86-64 relocation type or `None` if the relocation
+    /// does not come from an `Amd64` elf file
+    #[must_use]
+    pub fn r_type_x86_64(&self) -> Option<x86_64::RelocType> {
+        self.typed_r_type(ElfMachine::Amd64)
+    }
+
+    /// Returns the AArch64 relocation type or `None` if the relocation
+    /// does not come from an `Aarch64` elf file
+    #[must_use]
+    pub fn r_type_aarch64(&self) -> Option<aarch64::RelocType> {
+        self.typed_r_type(ElfMachine::Aarch64)
+    }
+
+    /// Returns the ARM relocation type or `None` if the relocation does
+    /// not come from an `Arm` elf file
+    #[must_use]
+    pub fn r_type_arm(&self) -> Option<arm::RelocType> {
+        self.typed_r_type(ElfMachine::Arm)
+    }
+
+    /// Returns the RISC-V relocation type or `None` if the relocation
+    /// does not come from a `Riscv` elf file
+    #[must_use]
+    pub fn r_type_riscv(&self) -> Option<riscv::RelocType> {
+        self.typed_r_type(ElfMachine::Riscv)
+    }
+
+    /// Converts the relocation type when the elf machine is `machine`
+    fn typed_r_type<T: From<u32>>(&self, machine: ElfMachine) -> Option<T> {
+        if self.machine != machine {
+            return None;
+        }
+        Some(T::from(self.r_type()))
+    }
+}
+
+impl<'a> Iterator for RelIterator<'a> {
+    type Item = RelEnt;
+    fn next(&mut self) -> Option<Self::Item> {
+        // If the number of relocations is zero then abort the iterator
+        if self.relnum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let relnum = core::mem::take(&mut self.relnum);
+
+            // Parse the relocation entry into the struct
+            let end = self.offset.checked_add(self.entsize)?;
+            self.rel = self
+                .rel
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
+                .ok()?;
+
+            // Calculate the next offset for the next relocation
+            self.offset = end;
+
+            // Subtract one from the number of the relocations
+            self.relnum = relnum - 1;
+
+            Some(self.rel)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.relnum, Some(self.relnum))
+    }
+}
+
+impl<'a> ExactSizeIterator for RelIterator<'a> {}
+
+impl<'a> RelIterator<'a> {
+    pub fn new(
+        sh: SectionHeader,
+        class: ElfClass,
+        machine: ElfMachine,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        // Construct a empty relocation for the relocation iterator
+        let mut rel = RelEnt::new();
+        rel.machine = machine;
+
+        // Sections without a fixed entry size can not be iterated
+        let relnum = sh.sh_size.checked_div(sh.sh_entsize).unwrap_or(0);
+        let offset = saturating_usize(sh.sh_offset);
+        let entsize = saturating_usize(sh.sh_entsize);
+        let relnum = checked_table_len(
+            elf,
+            offset,
+            entsize,
+            saturating_usize(relnum),
+            |ent| rel.parse(ent, class, data),
+        );
+
+        RelIterator {
+            rel,
+            offset,
+            entsize,
+            relnum,
+            class,
+            data,
+            elf,
+        }
+    }
+}
+
+impl From<u64> for RelrEntry {
+    fn from(value: u64) -> Self {
+        // The lowest bit tells the bitmaps apart from the addresses
+        if value & 1 == 0 {
+            RelrEntry::Address(value)
+        } else {
+            RelrEntry::Bitmap(value)
+        }
+    }
+}
+
+impl<'a> Iterator for RelrIterator<'a> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Size of an address in bytes for the elf class
+        let wordsize: u64 = match self.class {
+            ElfClass::Class32 => 4,
+            _ => 8,
+        };
+
+        loop {
+            // Yield the pending relocations of the current bitmap first
+            if self.bitmap != 0 {
+                let bit = self.bitmap.trailing_zeros() as u64;
+                self.bitmap &= self.bitmap - 1;
+                return Some(
+                    self.bitmap_base.wrapping_add(bit.wrapping_mul(wordsize)),
+                );
+            }
+
+            // If the number of entries is zero then abort the iterator
+            if self.relrnum == 0 {
+                return None;
+            }
+
+            // Parse the next entry
+            let end = self.offset.checked_add(wordsize as usize)?;
+            let word = if self.class == ElfClass::Class32 {
+                u32::endian_parse(self.offset..end, self.elf, &self.data)
+                    .ok()? as u64
+            } else {
+                u64::endian_parse(self.offset..end, self.elf, &self.data)
+                    .ok()?
+            };
+
+            // Calculate the next offset for the next entry
+            self.offset = end;
+
+            // Subtract one from the number of the entries
+            self.relrnum -= 1;
+
+            match RelrEntry::from(word) {
+                RelrEntry::Address(addr) => {
+                    // Following bitmaps are relative to the next word
+                    self.base = addr.wrapping_add(wordsize);
+                    return Some(addr);
+                }
+                RelrEntry::Bitmap(bitmap) => {
+                    // Each bitmap covers one word less than its bit count
+                    self.bitmap = bitmap >> 1;
+                    self.bitmap_base = self.base;
+                    self.base = self
+                        .base
+                        .wrapping_add((wordsize * 8 - 1) * wordsize);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> RelrIterator<'a> {
+    pub fn new(
+        sh: SectionHeader,
+        class: ElfClass,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        // Entries are always the size of an address
+        let wordsize = if class == ElfClass::Class32 { 4 } else { 8 };
+
+        RelrIterator {
+            offset: saturating_usize(sh.sh_offset),
+            relrnum: saturating_usize(sh.sh_size / wordsize),
+            base: 0,
+            bitmap: 0,
+            bitmap_base: 0,
+            class,
+            data,
+            elf,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/// RelocType specifies the AArch64 relocation types from the ELF ABI
+/// The variants follow the `R_AARCH64_*` constants
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RelocType {
+    /// `R_AARCH64_NONE` no relocation
+    None,
+    /// `R_AARCH64_ABS64` direct 64-bit value
+    Abs64,
+    /// `R_AARCH64_ABS32` direct 32-bit value
+    Abs32,
+    /// `R_AARCH64_ABS16` direct 16-bit value
+    Abs16,
+    /// `R_AARCH64_PREL64` PC relative 64-bit value
+    Prel64,
+    /// `R_AARCH64_PREL32` PC relative 32-bit value
+    Prel32,
+    /// `R_AARCH64_PREL16` PC relative 16-bit value
+    Prel16,
+    /// `R_AARCH64_ADR_PREL_PG_HI21` page of the PC relative address
+    AdrPrelPgHi21,
+    /// `R_AARCH64_ADD_ABS_LO12_NC` low 12 bits of the address for `add`
+    AddAbsLo12Nc,
+    /// `R_AARCH64_JUMP26` 26-bit PC relative branch
+    Jump26,
+    /// `R_AARCH64_CALL26` 26-bit PC relative call
+    Call26,
+    /// `R_AARCH64_LDST64_ABS_LO12_NC` low 12 bits of a 64-bit load
+    Ldst64AbsLo12Nc,
+    /// `R_AARCH64_ADR_GOT_PAGE` page of the GOT entry
+    AdrGotPage,
+    /// `R_AARCH64_LD64_GOT_LO12_NC` low 12 bits of the GOT entry
+    Ld64GotLo12Nc,
+    /// `R_AARCH64_COPY` copy the symbol at runtime
+    Copy,
+    /// `R_AARCH64_GLOB_DAT` create a GOT entry
+    GlobDat,
+    /// `R_AARCH64_JUMP_SLOT` create a PLT entry
+    JumpSlot,
+    /// `R_AARCH64_RELATIVE` adjust by the program base
+    Relative,
+    /// `R_AARCH64_TLS_DTPMOD` ID of the module containing the symbol
+    TlsDtpMod,
+    /// `R_AARCH64_TLS_DTPREL` offset in the TLS block of the module
+    TlsDtpRel,
+    /// `R_AARCH64_TLS_TPREL` offset in the initial TLS block
+    TlsTpRel,
+    /// `R_AARCH64_TLSDESC` TLS descriptor
+    TlsDesc,
+    /// `R_AARCH64_IRELATIVE` adjust indirectly by the program base
+    IRelative,
+    /// Relocation type not known by the parser
+    Unknown(u32),
+}
+
+impl From<u32> for RelocType {
+    fn from(value: u32) -> Self {
+        match value {
+            0    => RelocType::None,
+            257  => RelocType::Abs64,
+            258  => RelocType::Abs32,
+            259  => RelocType::Abs16,
+            260  => RelocType::Prel64,
+            261  => RelocType::Prel32,
+            262  => RelocType::Prel16,
+            275  => RelocType::AdrPrelPgHi21,
+            277  => RelocType::AddAbsLo12Nc,
+            282  => RelocType::Jump26,
+            283  => RelocType::Call26,
+            286  => RelocType::Ldst64AbsLo12Nc,
+            311  => RelocType::AdrGotPage,
+            312  => RelocType::Ld64GotLo12Nc,
+            1024 => RelocType::Copy,
+            1025 => RelocType::GlobDat,
+            1026 => RelocType::JumpSlot,
+            1027 => RelocType::Relative,
+            1028 => RelocType::TlsDtpMod,
+            1029 => RelocType::TlsDtpRel,
+            1030 => RelocType::TlsTpRel,
+            1031 => RelocType::TlsDesc,
+            1032 => RelocType::IRelative,
+            _    => RelocType::Unknown(value),
+        }
+    }
+}
+
+impl RelocType {
+    /// Returns the raw `R_AARCH64_*` value of the relocation type
+    pub fn raw_value(&self) -> u32 {
+        match self {
+            RelocType::None            => 0,
+            RelocType::Abs64           => 257,
+            RelocType::Abs32           => 258,
+            RelocType::Abs16           => 259,
+            RelocType::Prel64          => 260,
+            RelocType::Prel32          => 261,
+            RelocType::Prel16          => 262,
+            RelocType::AdrPrelPgHi21   => 275,
+            RelocType::AddAbsLo12Nc    => 277,
+            RelocType::Jump26          => 282,
+            RelocType::Call26          => 283,
+            RelocType::Ldst64AbsLo12Nc => 286,
+            RelocType::AdrGotPage      => 311,
+            RelocType::Ld64GotLo12Nc   => 312,
+            RelocType::Copy            => 1024,
+            RelocType::GlobDat         => 1025,
+            RelocType::JumpSlot        => 1026,
+            RelocType::Relative        => 1027,
+            RelocType::TlsDtpMod       => 1028,
+            RelocType::TlsDtpRel       => 1029,
+            RelocType::TlsTpRel        => 1030,
+            RelocType::TlsDesc         => 1031,
+            RelocType::IRelative       => 1032,
+            RelocType::Unknown(value)  => *value,
+        }
+    }
+}
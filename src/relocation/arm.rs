@@ -0,0 +1,148 @@
+/// RelocType specifies the ARM relocation types from the ELF ABI
+/// The variants follow the `R_ARM_*` constants
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RelocType {
+    /// `R_ARM_NONE` no relocation
+    None,
+    /// `R_ARM_PC24` deprecated 24-bit PC relative branch
+    Pc24,
+    /// `R_ARM_ABS32` direct 32-bit value
+    Abs32,
+    /// `R_ARM_REL32` PC relative 32-bit value
+    Rel32,
+    /// `R_ARM_ABS16` direct 16-bit value
+    Abs16,
+    /// `R_ARM_ABS12` direct 12-bit value
+    Abs12,
+    /// `R_ARM_THM_CALL` Thumb `bl` call
+    ThmCall,
+    /// `R_ARM_TLS_DESC` TLS descriptor
+    TlsDesc,
+    /// `R_ARM_TLS_DTPMOD32` ID of the module containing the symbol
+    TlsDtpMod32,
+    /// `R_ARM_TLS_DTPOFF32` offset in the TLS block of the module
+    TlsDtpOff32,
+    /// `R_ARM_TLS_TPOFF32` offset in the initial TLS block
+    TlsTpOff32,
+    /// `R_ARM_COPY` copy the symbol at runtime
+    Copy,
+    /// `R_ARM_GLOB_DAT` create a GOT entry
+    GlobDat,
+    /// `R_ARM_JUMP_SLOT` create a PLT entry
+    JumpSlot,
+    /// `R_ARM_RELATIVE` adjust by the program base
+    Relative,
+    /// `R_ARM_GOTOFF32` 32-bit offset to the GOT
+    GotOff32,
+    /// `R_ARM_BASE_PREL` PC relative offset to the GOT
+    BasePrel,
+    /// `R_ARM_GOT_BREL` offset of the GOT entry
+    GotBrel,
+    /// `R_ARM_PLT32` deprecated PLT branch
+    Plt32,
+    /// `R_ARM_CALL` ARM `bl` call
+    Call,
+    /// `R_ARM_JUMP24` ARM branch
+    Jump24,
+    /// `R_ARM_THM_JUMP24` Thumb branch
+    ThmJump24,
+    /// `R_ARM_MOVW_ABS_NC` low 16 bits of the address for `movw`
+    MovwAbsNc,
+    /// `R_ARM_MOVT_ABS` high 16 bits of the address for `movt`
+    MovtAbs,
+    /// `R_ARM_THM_MOVW_ABS_NC` low 16 bits of the address for Thumb `movw`
+    ThmMovwAbsNc,
+    /// `R_ARM_THM_MOVT_ABS` high 16 bits of the address for Thumb `movt`
+    ThmMovtAbs,
+    /// `R_ARM_TLS_GD32` PC relative offset to the GD GOT entry
+    TlsGd32,
+    /// `R_ARM_TLS_LDM32` PC relative offset to the LD GOT entry
+    TlsLdm32,
+    /// `R_ARM_TLS_IE32` PC relative offset to the IE GOT entry
+    TlsIe32,
+    /// `R_ARM_TLS_LE32` offset in the initial TLS block
+    TlsLe32,
+    /// `R_ARM_IRELATIVE` adjust indirectly by the program base
+    IRelative,
+    /// Relocation type not known by the parser
+    Unknown(u32),
+}
+
+impl From<u32> for RelocType {
+    fn from(value: u32) -> Self {
+        match value {
+            0   => RelocType::None,
+            1   => RelocType::Pc24,
+            2   => RelocType::Abs32,
+            3   => RelocType::Rel32,
+            5   => RelocType::Abs16,
+            6   => RelocType::Abs12,
+            10  => RelocType::ThmCall,
+            13  => RelocType::TlsDesc,
+            17  => RelocType::TlsDtpMod32,
+            18  => RelocType::TlsDtpOff32,
+            19  => RelocType::TlsTpOff32,
+            20  => RelocType::Copy,
+            21  => RelocType::GlobDat,
+            22  => RelocType::JumpSlot,
+            23  => RelocType::Relative,
+            24  => RelocType::GotOff32,
+            25  => RelocType::BasePrel,
+            26  => RelocType::GotBrel,
+            27  => RelocType::Plt32,
+            28  => RelocType::Call,
+            29  => RelocType::Jump24,
+            30  => RelocType::ThmJump24,
+            43  => RelocType::MovwAbsNc,
+            44  => RelocType::MovtAbs,
+            47  => RelocType::ThmMovwAbsNc,
+            48  => RelocType::ThmMovtAbs,
+            104 => RelocType::TlsGd32,
+            105 => RelocType::TlsLdm32,
+            107 => RelocType::TlsIe32,
+            108 => RelocType::TlsLe32,
+            160 => RelocType::IRelative,
+            _   => RelocType::Unknown(value),
+        }
+    }
+}
+
+impl RelocType {
+    /// Returns the raw `R_ARM_*` value of the relocation type
+    pub fn raw_value(&self) -> u32 {
+        match self {
+            RelocType::None           => 0,
+            RelocType::Pc24           => 1,
+            RelocType::Abs32          => 2,
+            RelocType::Rel32          => 3,
+            RelocType::Abs16          => 5,
+            RelocType::Abs12          => 6,
+            RelocType::ThmCall        => 10,
+            RelocType::TlsDesc        => 13,
+            RelocType::TlsDtpMod32    => 17,
+            RelocType::TlsDtpOff32    => 18,
+            RelocType::TlsTpOff32     => 19,
+            RelocType::Copy           => 20,
+            RelocType::GlobDat        => 21,
+            RelocType::JumpSlot       => 22,
+            RelocType::Relative       => 23,
+            RelocType::GotOff32       => 24,
+            RelocType::BasePrel       => 25,
+            RelocType::GotBrel        => 26,
+            RelocType::Plt32          => 27,
+            RelocType::Call           => 28,
+            RelocType::Jump24         => 29,
+            RelocType::ThmJump24      => 30,
+            RelocType::MovwAbsNc      => 43,
+            RelocType::MovtAbs        => 44,
+            RelocType::ThmMovwAbsNc   => 47,
+            RelocType::ThmMovtAbs     => 48,
+            RelocType::TlsGd32        => 104,
+            RelocType::TlsLdm32       => 105,
+            RelocType::TlsIe32        => 107,
+            RelocType::TlsLe32        => 108,
+            RelocType::IRelative      => 160,
+            RelocType::Unknown(value) => *value,
+        }
+    }
+}
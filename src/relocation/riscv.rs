@@ -0,0 +1,190 @@
+/// RelocType specifies the RISC-V relocation types from the psABI
+/// The variants follow the `R_RISCV_*` constants
+/// RISC-V has no `GLOB_DAT` type as the GOT entries use `R_RISCV_32`
+/// and `R_RISCV_64`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RelocType {
+    /// `R_RISCV_NONE` no relocation
+    None,
+    /// `R_RISCV_32` direct 32-bit value
+    Abs32,
+    /// `R_RISCV_64` direct 64-bit value
+    Abs64,
+    /// `R_RISCV_RELATIVE` adjust by the program base
+    Relative,
+    /// `R_RISCV_COPY` copy the symbol at runtime
+    Copy,
+    /// `R_RISCV_JUMP_SLOT` create a PLT entry
+    JumpSlot,
+    /// `R_RISCV_TLS_DTPMOD32` 32-bit ID of the module of the symbol
+    TlsDtpMod32,
+    /// `R_RISCV_TLS_DTPMOD64` 64-bit ID of the module of the symbol
+    TlsDtpMod64,
+    /// `R_RISCV_TLS_DTPREL32` 32-bit offset in the TLS block
+    TlsDtpRel32,
+    /// `R_RISCV_TLS_DTPREL64` 64-bit offset in the TLS block
+    TlsDtpRel64,
+    /// `R_RISCV_TLS_TPREL32` 32-bit offset in the initial TLS block
+    TlsTpRel32,
+    /// `R_RISCV_TLS_TPREL64` 64-bit offset in the initial TLS block
+    TlsTpRel64,
+    /// `R_RISCV_TLSDESC` TLS descriptor
+    TlsDesc,
+    /// `R_RISCV_BRANCH` 12-bit PC relative branch
+    Branch,
+    /// `R_RISCV_JAL` 20-bit PC relative jump
+    Jal,
+    /// `R_RISCV_CALL` PC relative `auipc` and `jalr` call
+    Call,
+    /// `R_RISCV_CALL_PLT` deprecated PLT call
+    CallPlt,
+    /// `R_RISCV_GOT_HI20` high 20 bits of the PC relative GOT entry
+    GotHi20,
+    /// `R_RISCV_TLS_GOT_HI20` high 20 bits of the IE GOT entry
+    TlsGotHi20,
+    /// `R_RISCV_TLS_GD_HI20` high 20 bits of the GD GOT entry
+    TlsGdHi20,
+    /// `R_RISCV_PCREL_HI20` high 20 bits of a PC relative value
+    PcrelHi20,
+    /// `R_RISCV_PCREL_LO12_I` low 12 bits of a PC relative value
+    PcrelLo12I,
+    /// `R_RISCV_PCREL_LO12_S` low 12 bits of a PC relative store
+    PcrelLo12S,
+    /// `R_RISCV_HI20` high 20 bits of the address
+    Hi20,
+    /// `R_RISCV_LO12_I` low 12 bits of the address
+    Lo12I,
+    /// `R_RISCV_LO12_S` low 12 bits of the address for a store
+    Lo12S,
+    /// `R_RISCV_TPREL_HI20` high 20 bits of the TLS offset
+    TpRelHi20,
+    /// `R_RISCV_TPREL_LO12_I` low 12 bits of the TLS offset
+    TpRelLo12I,
+    /// `R_RISCV_TPREL_LO12_S` low 12 bits of the TLS offset for a store
+    TpRelLo12S,
+    /// `R_RISCV_TPREL_ADD` marker of the thread pointer addition
+    TpRelAdd,
+    /// `R_RISCV_ADD32` 32-bit label addition
+    Add32,
+    /// `R_RISCV_ADD64` 64-bit label addition
+    Add64,
+    /// `R_RISCV_SUB32` 32-bit label subtraction
+    Sub32,
+    /// `R_RISCV_SUB64` 64-bit label subtraction
+    Sub64,
+    /// `R_RISCV_ALIGN` alignment padding the linker may remove
+    Align,
+    /// `R_RISCV_RVC_BRANCH` 8-bit PC relative compressed branch
+    RvcBranch,
+    /// `R_RISCV_RVC_JUMP` 11-bit PC relative compressed jump
+    RvcJump,
+    /// `R_RISCV_RELAX` the previous instruction can be relaxed
+    Relax,
+    /// `R_RISCV_SET32` 32-bit local label value
+    Set32,
+    /// `R_RISCV_32_PCREL` PC relative 32-bit value
+    Pcrel32,
+    /// `R_RISCV_IRELATIVE` adjust indirectly by the program base
+    IRelative,
+    /// Relocation type not known by the parser
+    Unknown(u32),
+}
+
+impl From<u32> for RelocType {
+    fn from(value: u32) -> Self {
+        match value {
+            0  => RelocType::None,
+            1  => RelocType::Abs32,
+            2  => RelocType::Abs64,
+            3  => RelocType::Relative,
+            4  => RelocType::Copy,
+            5  => RelocType::JumpSlot,
+            6  => RelocType::TlsDtpMod32,
+            7  => RelocType::TlsDtpMod64,
+            8  => RelocType::TlsDtpRel32,
+            9  => RelocType::TlsDtpRel64,
+            10 => RelocType::TlsTpRel32,
+            11 => RelocType::TlsTpRel64,
+            12 => RelocType::TlsDesc,
+            16 => RelocType::Branch,
+            17 => RelocType::Jal,
+            18 => RelocType::Call,
+            19 => RelocType::CallPlt,
+            20 => RelocType::GotHi20,
+            21 => RelocType::TlsGotHi20,
+            22 => RelocType::TlsGdHi20,
+            23 => RelocType::PcrelHi20,
+            24 => RelocType::PcrelLo12I,
+            25 => RelocType::PcrelLo12S,
+            26 => RelocType::Hi20,
+            27 => RelocType::Lo12I,
+            28 => RelocType::Lo12S,
+            29 => RelocType::TpRelHi20,
+            30 => RelocType::TpRelLo12I,
+            31 => RelocType::TpRelLo12S,
+            32 => RelocType::TpRelAdd,
+            35 => RelocType::Add32,
+            36 => RelocType::Add64,
+            39 => RelocType::Sub32,
+            40 => RelocType::Sub64,
+            43 => RelocType::Align,
+            44 => RelocType::RvcBranch,
+            45 => RelocType::RvcJump,
+            51 => RelocType::Relax,
+            56 => RelocType::Set32,
+            57 => RelocType::Pcrel32,
+            58 => RelocType::IRelative,
+            _  => RelocType::Unknown(value),
+        }
+    }
+}
+
+impl RelocType {
+    /// Returns the raw `R_RISCV_*` value of the relocation type
+    pub fn raw_value(&self) -> u32 {
+        match self {
+            RelocType::None           => 0,
+            RelocType::Abs32          => 1,
+            RelocType::Abs64          => 2,
+            RelocType::Relative       => 3,
+            RelocType::Copy           => 4,
+            RelocType::JumpSlot       => 5,
+            RelocType::TlsDtpMod32    => 6,
+            RelocType::TlsDtpMod64    => 7,
+            RelocType::TlsDtpRel32    => 8,
+            RelocType::TlsDtpRel64    => 9,
+            RelocType::TlsTpRel32     => 10,
+            RelocType::TlsTpRel64     => 11,
+            RelocType::TlsDesc        => 12,
+            RelocType::Branch         => 16,
+            RelocType::Jal            => 17,
+            RelocType::Call           => 18,
+            RelocType::CallPlt        => 19,
+            RelocType::GotHi20        => 20,
+            RelocType::TlsGotHi20     => 21,
+            RelocType::TlsGdHi20      => 22,
+            RelocType::PcrelHi20      => 23,
+            RelocType::PcrelLo12I     => 24,
+            RelocType::PcrelLo12S     => 25,
+            RelocType::Hi20           => 26,
+            RelocType::Lo12I          => 27,
+            RelocType::Lo12S          => 28,
+            RelocType::TpRelHi20      => 29,
+            RelocType::TpRelLo12I     => 30,
+            RelocType::TpRelLo12S     => 31,
+            RelocType::TpRelAdd       => 32,
+            RelocType::Add32          => 35,
+            RelocType::Add64          => 36,
+            RelocType::Sub32          => 39,
+            RelocType::Sub64          => 40,
+            RelocType::Align          => 43,
+            RelocType::RvcBranch      => 44,
+            RelocType::RvcJump        => 45,
+            RelocType::Relax          => 51,
+            RelocType::Set32          => 56,
+            RelocType::Pcrel32        => 57,
+            RelocType::IRelative      => 58,
+            RelocType::Unknown(value) => *value,
+        }
+    }
+}
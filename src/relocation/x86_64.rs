@@ -0,0 +1,165 @@
+/// RelocType specifies the x86-64 relocation types from the psABI
+/// The variants follow the `R_X86_64_*` constants
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RelocType {
+    /// `R_X86_64_NONE` no relocation
+    None,
+    /// `R_X86_64_64` direct 64-bit value
+    Abs64,
+    /// `R_X86_64_PC32` PC relative 32-bit signed value
+    Pc32,
+    /// `R_X86_64_GOT32` 32-bit GOT entry
+    Got32,
+    /// `R_X86_64_PLT32` 32-bit PLT address
+    Plt32,
+    /// `R_X86_64_COPY` copy the symbol at runtime
+    Copy,
+    /// `R_X86_64_GLOB_DAT` create a GOT entry
+    GlobDat,
+    /// `R_X86_64_JUMP_SLOT` create a PLT entry
+    JumpSlot,
+    /// `R_X86_64_RELATIVE` adjust by the program base
+    Relative,
+    /// `R_X86_64_GOTPCREL` 32-bit PC relative offset to the GOT entry
+    GotPcRel,
+    /// `R_X86_64_32` direct 32-bit zero extended value
+    Abs32,
+    /// `R_X86_64_32S` direct 32-bit sign extended value
+    Abs32S,
+    /// `R_X86_64_16` direct 16-bit zero extended value
+    Abs16,
+    /// `R_X86_64_PC16` 16-bit sign extended PC relative value
+    Pc16,
+    /// `R_X86_64_8` direct 8-bit sign extended value
+    Abs8,
+    /// `R_X86_64_PC8` 8-bit sign extended PC relative value
+    Pc8,
+    /// `R_X86_64_DTPMOD64` ID of the module containing the symbol
+    DtpMod64,
+    /// `R_X86_64_DTPOFF64` offset in the TLS block of the module
+    DtpOff64,
+    /// `R_X86_64_TPOFF64` offset in the initial TLS block
+    TpOff64,
+    /// `R_X86_64_TLSGD` PC relative offset to the GD GOT entry
+    TlsGd,
+    /// `R_X86_64_TLSLD` PC relative offset to the LD GOT entry
+    TlsLd,
+    /// `R_X86_64_DTPOFF32` 32-bit offset in the TLS block of the module
+    DtpOff32,
+    /// `R_X86_64_GOTTPOFF` PC relative offset to the IE GOT entry
+    GotTpOff,
+    /// `R_X86_64_TPOFF32` 32-bit offset in the initial TLS block
+    TpOff32,
+    /// `R_X86_64_PC64` PC relative 64-bit value
+    Pc64,
+    /// `R_X86_64_GOTOFF64` 64-bit offset to the GOT
+    GotOff64,
+    /// `R_X86_64_GOTPC32` 32-bit PC relative offset to the GOT
+    GotPc32,
+    /// `R_X86_64_SIZE32` 32-bit size of the symbol
+    Size32,
+    /// `R_X86_64_SIZE64` 64-bit size of the symbol
+    Size64,
+    /// `R_X86_64_GOTPC32_TLSDESC` PC relative offset to the TLS descriptor
+    GotPc32TlsDesc,
+    /// `R_X86_64_TLSDESC_CALL` marker of the call through a TLS descriptor
+    TlsDescCall,
+    /// `R_X86_64_TLSDESC` TLS descriptor
+    TlsDesc,
+    /// `R_X86_64_IRELATIVE` adjust indirectly by the program base
+    IRelative,
+    /// `R_X86_64_GOTPCRELX` relaxable load from the GOT entry
+    GotPcRelX,
+    /// `R_X86_64_REX_GOTPCRELX` relaxable load with a REX prefix from the
+    /// GOT entry
+    RexGotPcRelX,
+    /// Relocation type not known by the parser
+    Unknown(u32),
+}
+
+impl From<u32> for RelocType {
+    fn from(value: u32) -> Self {
+        match value {
+            0  => RelocType::None,
+            1  => RelocType::Abs64,
+            2  => RelocType::Pc32,
+            3  => RelocType::Got32,
+            4  => RelocType::Plt32,
+            5  => RelocType::Copy,
+            6  => RelocType::GlobDat,
+            7  => RelocType::JumpSlot,
+            8  => RelocType::Relative,
+            9  => RelocType::GotPcRel,
+            10 => RelocType::Abs32,
+            11 => RelocType::Abs32S,
+            12 => RelocType::Abs16,
+            13 => RelocType::Pc16,
+            14 => RelocType::Abs8,
+            15 => RelocType::Pc8,
+            16 => RelocType::DtpMod64,
+            17 => RelocType::DtpOff64,
+            18 => RelocType::TpOff64,
+            19 => RelocType::TlsGd,
+            20 => RelocType::TlsLd,
+            21 => RelocType::DtpOff32,
+            22 => RelocType::GotTpOff,
+            23 => RelocType::TpOff32,
+            24 => RelocType::Pc64,
+            25 => RelocType::GotOff64,
+            26 => RelocType::GotPc32,
+            32 => RelocType::Size32,
+            33 => RelocType::Size64,
+            34 => RelocType::GotPc32TlsDesc,
+            35 => RelocType::TlsDescCall,
+            36 => RelocType::TlsDesc,
+            37 => RelocType::IRelative,
+            41 => RelocType::GotPcRelX,
+            42 => RelocType::RexGotPcRelX,
+            _  => RelocType::Unknown(value),
+        }
+    }
+}
+
+impl RelocType {
+    /// Returns the raw `R_X86_64_*` value of the relocation type
+    pub fn raw_value(&self) -> u32 {
+        match self {
+            RelocType::None           => 0,
+            RelocType::Abs64          => 1,
+            RelocType::Pc32           => 2,
+            RelocType::Got32          => 3,
+            RelocType::Plt32          => 4,
+            RelocType::Copy           => 5,
+            RelocType::GlobDat        => 6,
+            RelocType::JumpSlot       => 7,
+            RelocType::Relative       => 8,
+            RelocType::GotPcRel       => 9,
+            RelocType::Abs32          => 10,
+            RelocType::Abs32S         => 11,
+            RelocType::Abs16          => 12,
+            RelocType::Pc16           => 13,
+            RelocType::Abs8           => 14,
+            RelocType::Pc8            => 15,
+            RelocType::DtpMod64       => 16,
+            RelocType::DtpOff64       => 17,
+            RelocType::TpOff64        => 18,
+            RelocType::TlsGd          => 19,
+            RelocType::TlsLd          => 20,
+            RelocType::DtpOff32       => 21,
+            RelocType::GotTpOff       => 22,
+            RelocType::TpOff32        => 23,
+            RelocType::Pc64           => 24,
+            RelocType::GotOff64       => 25,
+            RelocType::GotPc32        => 26,
+            RelocType::Size32         => 32,
+            RelocType::Size64         => 33,
+            RelocType::GotPc32TlsDesc => 34,
+            RelocType::TlsDescCall    => 35,
+            RelocType::TlsDesc        => 36,
+            RelocType::IRelative      => 37,
+            RelocType::GotPcRelX      => 41,
+            RelocType::RexGotPcRelX   => 42,
+            RelocType::Unknown(value) => *value,
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::Result;
+use crate::Error;
+use crate::utils::{align_up, contains, narrow, overlaps, saturating_usize};
+use crate::utils::checked_table_len;
 use crate::utils::Integer;
 use crate::file::{ElfData, ElfClass};
 
@@ -35,8 +38,25 @@ pub const SHF_ORDERED: u32 = 1 << 30;
 /// Section is excluded unless referenced or allocated (Solaris)
 pub const SHF_EXCLUDE: u32 = 1 << 31;
 
+/// Undefined section index
+pub const SHN_UNDEF: u16 = 0;
+/// Start of the reserved indices which are not section indices
+pub const SHN_LORESERVE: u16 = 0xff00;
+/// Absolute values which are not affected by relocation
+pub const SHN_ABS: u16 = 0xfff1;
+/// Common symbols which are not yet allocated
+pub const SHN_COMMON: u16 = 0xfff2;
+/// Index is stored in an extra table or in the first section header
+pub const SHN_XINDEX: u16 = 0xffff;
+
+/// ZLIB/DEFLATE compression algorithm
+pub const ELFCOMPRESS_ZLIB: u32 = 1;
+/// Zstandard compression algorithm
+pub const ELFCOMPRESS_ZSTD: u32 = 2;
+
 /// Section header stores data about the sections of the elf file
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionHeader {
     /// Identifies section name as indexes which is an offset of shstrtab
     pub sh_name: u32,
@@ -45,26 +65,27 @@ pub struct SectionHeader {
     /// Section flags describe sections permissions in memory
     pub sh_flags: SectionFlags,
     /// Section virtual address at the execution
-    pub sh_addr: usize,
+    pub sh_addr: u64,
     /// Section offset at the file image
-    pub sh_offset: usize,
+    pub sh_offset: u64,
     /// Section size in bytes
-    pub sh_size: usize,
+    pub sh_size: u64,
     /// Contains the section index of the associated section and info depends
     /// on the `sh_type`
     pub sh_link: u32,
     /// Additional section information which also depends on the `sh_type`
     pub sh_info: u32,
     /// Section alignment which must be a power of two
-    pub sh_addralign: usize,
+    pub sh_addralign: u64,
     /// Entry size in bytes for fixed size sections otherwise it is zero
-    pub sh_entsize: usize,
+    pub sh_entsize: u64,
     /// Used to identify the section header number to be linked to shstrtab
     pub sh_ndx: usize,
 }
 
 /// Enum to identify section types
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SectionType {
     None,
     /// Section Table entry unused
@@ -106,22 +127,42 @@ pub enum SectionType {
     /// Number of defined types
     ShtNum,
     /// Os Specific
-    ShtOs,
+    ShtOs(u32),
     /// Gnu object attributes
     ShtGnuAttributes,
     /// Gnu style hash tables
     ShtGnuHash,
     /// Gnu prelink library list
     ShtGnuLibList,
+    /// Gnu symbol version definitions
+    ShtGnuVerDef,
+    /// Gnu symbol version dependencies
+    ShtGnuVerNeed,
+    /// Gnu symbol version table
+    ShtGnuVerSym,
     /// Processor specific sections
-    ShtProc,
+    ShtProc(u32),
     /// Application specific sections
-    ShtUser,
+    ShtUser(u32),
+    /// Section type without a name
+    Unknown(u32),
 }
 
 /// SectionFlags tuple struct to implement some is_* functions on
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub struct SectionFlags(usize);
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectionFlags(u64);
+
+/// Compression header found at the start of the `SHF_COMPRESSED` sections
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CompressedSectionHeader {
+    /// Compression algorithm e.g. `ELFCOMPRESS_ZLIB`
+    pub ch_type: u32,
+    /// Size in bytes of the uncompressed data
+    pub ch_size: u64,
+    /// Alignment of the uncompressed data
+    pub ch_addralign: u64,
+}
 
 /// Helper type to implement the iterator type on
 /// The best is for the `section_iter()` function to be called
@@ -138,7 +179,7 @@ pub struct SectionIterator<'a> {
     shentsize: u16,
     /// Number of header entries also used as
     /// the index of the iteration
-    shnum: u16,
+    shnum: usize,
     /// Elf class used for parsing
     class: ElfClass,
     /// Elf endianness used for parsing
@@ -147,6 +188,306 @@ pub struct SectionIterator<'a> {
     elf: &'a [u8],
 }
 
+/// Symbol table entry stores the symbols of the `ShtSymTab` and
+/// `ShtDynSym` sections
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymTabEnt {
+    /// Symbol name as an index into the linked string table
+    pub st_name: u32,
+    /// Symbol type taken from the low nibble of `st_info`
+    pub st_info: SymType,
+    /// Symbol binding taken from the high nibble of `st_info`
+    pub st_bind: SymBinding,
+    /// Symbol visibility
+    pub st_other: u8,
+    /// Index of the section the symbol is defined in
+    pub st_shndx: u16,
+    /// Symbol value which is usually an address
+    pub st_value: u64,
+    /// Size of the object the symbol refers to in bytes
+    pub st_size: u64,
+}
+
+/// Symbol resolved by name with everything needed to use it
+/// The binding and the visibility are found in the symbol `entry`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ResolvedSymbol<'a> {
+    /// Symbol table entry holding the address, size and binding
+    pub entry: SymTabEnt,
+    /// Symbol name from the linked string table
+    pub name: &'a str,
+    /// Section the symbol is defined in or `None` for the undefined,
+    /// absolute and common symbols
+    pub section: Option<SectionHeader>,
+    /// GNU version name of the dynamic symbol like `GLIBC_2.2.5`
+    pub version: Option<&'a str>,
+}
+
+/// Enum to identify symbol types
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymType {
+    None,
+    /// Symbol type is unspecified
+    NoType,
+    /// Symbol is a data object
+    Object,
+    /// Symbol is a code object
+    Func,
+    /// Symbol associated with a section
+    Section,
+    /// Symbol's name is file name
+    File,
+    /// Symbol is a common data object
+    Common,
+    /// Symbol is thread-local data object
+    Tls,
+    /// Symbol is an indirect function whose address is returned by
+    /// calling it (`STT_GNU_IFUNC`)
+    GnuIfunc,
+    /// Start of OS specific symbol types
+    LoOs,
+    /// End of OS specific symbol types
+    HiOs,
+    /// Start of processor specific symbol types
+    LoProc,
+    /// End of processor specific symbol types
+    HiProc,
+    /// Symbol type without a name
+    Unknown(u8),
+}
+
+/// Enum to identify symbol bindings
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymBinding {
+    #[default]
+    None,
+    /// Local symbol not visible outside the object file
+    Local,
+    /// Global symbol visible to all object files
+    Global,
+    /// Global symbol with lower precedence
+    Weak,
+    /// Start of OS specific symbol bindings
+    LoOs,
+    /// End of OS specific symbol bindings
+    HiOs,
+    /// Start of processor specific symbol bindings
+    LoProc,
+    /// End of processor specific symbol bindings
+    HiProc,
+    /// Symbol binding without a name
+    Unknown(u8),
+}
+
+/// Enum to identify symbol visibilities
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Hash)]
+pub enum SymVisibility {
+    /// Visibility is given by the symbol binding
+    #[default]
+    Default,
+    /// Processor specific hidden visibility
+    Internal,
+    /// Symbol not visible outside the component defining it
+    Hidden,
+    /// Visible symbol which can not be preempted
+    Protected,
+}
+
+/// Enum to identify the section a symbol is defined relative to
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SectionIndex {
+    /// Index of a section header in the section header table
+    Index(u16),
+    /// Undefined symbol
+    Undef,
+    /// Absolute symbol which is not affected by relocation
+    Abs,
+    /// Common symbol which is not yet allocated
+    Common,
+    /// Index is stored in the `ShtSymTabShndx` section
+    Xindex,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `symtab_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct SymTabIterator<'a> {
+    /// Owned symbol table entry struct to address
+    symbol: SymTabEnt,
+    /// Symbol table offset in the elf file
+    offset: usize,
+    /// Symbol table entry size
+    entsize: usize,
+    /// Number of symbol table entries also used as
+    /// the index of the iteration
+    symnum: usize,
+    /// Elf class used for parsing
+    class: ElfClass,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Map of the section names to their section headers
+/// The best is for the `build_section_index()` function to be called
+/// on the elf struct
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct SectionNameIndex<'a> {
+    /// Section headers ordered by their names
+    map: alloc::collections::BTreeMap<&'a str, SectionHeader>,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `strtab_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct StrTabIterator<'a> {
+    /// String table section data
+    data: &'a [u8],
+    /// Offset of the next string in the string table
+    pos: usize,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `kernel_module_info()` function to be called
+/// on the elf struct
+/// The iterator yields the `key=value` strings of the `.modinfo` section
+/// split at the first `=` where entries without one have an empty value
+#[derive(Debug, Clone, Copy)]
+pub struct ModinfoIterator<'a> {
+    /// Strings of the section data
+    strings: StrTabIterator<'a>,
+}
+
+/// Reference to the separate debug file stored in the `.gnu_debuglink`
+/// section of a file stripped with `strip --keep-debug`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GnuDebugLink<'a> {
+    /// Name of the separate debug file
+    pub filename: &'a str,
+    /// CRC32 of the separate debug file contents
+    pub crc: u32,
+}
+
+impl From<u32> for SectionType {
+    fn from(value: u32) -> Self {
+        match value {
+            0x00000000 => SectionType::ShtNull,
+            0x00000001 => SectionType::ShtProgBits,
+            0x00000002 => SectionType::ShtSymTab,
+            0x00000003 => SectionType::ShtStrTab,
+            0x00000004 => SectionType::ShtRela,
+            0x00000005 => SectionType::ShtHash,
+            0x00000006 => SectionType::ShtDynamic,
+            0x00000007 => SectionType::ShtNotes,
+            0x00000008 => SectionType::ShtNoBits,
+            0x00000009 => SectionType::ShtRel,
+            0x0000000a => SectionType::ShtShlib,
+            0x0000000b => SectionType::ShtDynSym,
+            0x0000000e => SectionType::ShtInitArray,
+            0x0000000f => SectionType::ShtFInitArray,
+            0x00000010 => SectionType::ShtPreInitArray,
+            0x00000011 => SectionType::ShtGroup,
+            0x00000012 => SectionType::ShtSymTabShndx,
+            0x00000013 => SectionType::ShtRelr,
+            0x00000014 => SectionType::ShtNum,
+            0x6ffffff5 => SectionType::ShtGnuAttributes,
+            0x6ffffff6 => SectionType::ShtGnuHash,
+            0x6ffffff7 => SectionType::ShtGnuLibList,
+            0x6ffffffd => SectionType::ShtGnuVerDef,
+            0x6ffffffe => SectionType::ShtGnuVerNeed,
+            0x6fffffff => SectionType::ShtGnuVerSym,
+            0x60000000..=0x6fffffff => SectionType::ShtOs(value),
+            0x70000000..=0x7fffffff => SectionType::ShtProc(value),
+            0x80000000..=0xffffffff => SectionType::ShtUser(value),
+            _ => SectionType::Unknown(value),
+        }
+    }
+}
+
+impl SectionType {
+    /// Returns the raw `sh_type` value of the section type
+    /// Range and unknown variants return the value they hold and `None`
+    /// returns zero as it has no value
+    pub fn raw_value(self) -> u32 {
+        match self {
+            SectionType::None             => 0x00000000,
+            SectionType::ShtNull          => 0x00000000,
+            SectionType::ShtProgBits      => 0x00000001,
+            SectionType::ShtSymTab        => 0x00000002,
+            SectionType::ShtStrTab        => 0x00000003,
+            SectionType::ShtRela          => 0x00000004,
+            SectionType::ShtHash          => 0x00000005,
+            SectionType::ShtDynamic       => 0x00000006,
+            SectionType::ShtNotes         => 0x00000007,
+            SectionType::ShtNoBits        => 0x00000008,
+            SectionType::ShtRel           => 0x00000009,
+            SectionType::ShtShlib         => 0x0000000a,
+            SectionType::ShtDynSym        => 0x0000000b,
+            SectionType::ShtInitArray     => 0x0000000e,
+            SectionType::ShtFInitArray    => 0x0000000f,
+            SectionType::ShtPreInitArray  => 0x00000010,
+            SectionType::ShtGroup         => 0x00000011,
+            SectionType::ShtSymTabShndx   => 0x00000012,
+            SectionType::ShtRelr          => 0x00000013,
+            SectionType::ShtNum           => 0x00000014,
+            SectionType::ShtGnuAttributes => 0x6ffffff5,
+            SectionType::ShtGnuHash       => 0x6ffffff6,
+            SectionType::ShtGnuLibList    => 0x6ffffff7,
+            SectionType::ShtGnuVerDef     => 0x6ffffffd,
+            SectionType::ShtGnuVerNeed    => 0x6ffffffe,
+            SectionType::ShtGnuVerSym     => 0x6fffffff,
+            SectionType::ShtOs(value)     => value,
+            SectionType::ShtProc(value)   => value,
+            SectionType::ShtUser(value)   => value,
+            SectionType::Unknown(value)   => value,
+        }
+    }
+}
+
+impl core::fmt::Display for SectionType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SectionType::None             => "UNKNOWN",
+            SectionType::ShtNull          => "NULL",
+            SectionType::ShtProgBits      => "PROGBITS",
+            SectionType::ShtSymTab        => "SYMTAB",
+            SectionType::ShtStrTab        => "STRTAB",
+            SectionType::ShtRela          => "RELA",
+            SectionType::ShtHash          => "HASH",
+            SectionType::ShtDynamic       => "DYNAMIC",
+            SectionType::ShtNotes         => "NOTE",
+            SectionType::ShtNoBits        => "NOBITS",
+            SectionType::ShtRel           => "REL",
+            SectionType::ShtShlib         => "SHLIB",
+            SectionType::ShtDynSym        => "DYNSYM",
+            SectionType::ShtInitArray     => "INIT_ARRAY",
+            SectionType::ShtFInitArray    => "FINI_ARRAY",
+            SectionType::ShtPreInitArray  => "PREINIT_ARRAY",
+            SectionType::ShtGroup         => "GROUP",
+            SectionType::ShtSymTabShndx   => "SYMTAB SECTION INDICES",
+            SectionType::ShtRelr          => "RELR",
+            SectionType::ShtNum           => "NUM",
+            SectionType::ShtOs(_)         => "LOOS+",
+            SectionType::ShtGnuAttributes => "GNU_ATTRIBUTES",
+            SectionType::ShtGnuHash       => "GNU_HASH",
+            SectionType::ShtGnuLibList    => "GNU_LIBLIST",
+            SectionType::ShtGnuVerDef     => "VERDEF",
+            SectionType::ShtGnuVerNeed    => "VERNEED",
+            SectionType::ShtGnuVerSym     => "VERSYM",
+            SectionType::ShtProc(_)       => "LOPROC+",
+            SectionType::ShtUser(_)       => "LOUSER+",
+            SectionType::Unknown(_)       => "UNKNOWN",
+        })
+    }
+}
+
 impl Default for SectionHeader {
     fn default() -> Self {
         Self::new()
@@ -172,6 +513,7 @@ impl SectionHeader {
     }
 
     /// Parse the program header and populate the fields
+    #[inline]
     pub fn parse(
         mut self,
         elf: &[u8],
@@ -182,50 +524,23 @@ impl SectionHeader {
         self.sh_name = u32::endian_parse(0x00..0x04, elf, &data)?;
 
         // Get the section type
-        self.sh_type = match elf.get(0x04..0x08) {
-            Some(&[0x00, 0x00, 0x00, 0x00]) => SectionType::ShtNull,
-            Some(&[0x01, 0x00, 0x00, 0x00]) => SectionType::ShtProgBits,
-            Some(&[0x02, 0x00, 0x00, 0x00]) => SectionType::ShtSymTab,
-            Some(&[0x03, 0x00, 0x00, 0x00]) => SectionType::ShtStrTab,
-            Some(&[0x04, 0x00, 0x00, 0x00]) => SectionType::ShtRela,
-            Some(&[0x05, 0x00, 0x00, 0x00]) => SectionType::ShtHash,
-            Some(&[0x06, 0x00, 0x00, 0x00]) => SectionType::ShtDynamic,
-            Some(&[0x07, 0x00, 0x00, 0x00]) => SectionType::ShtNotes,
-            Some(&[0x08, 0x00, 0x00, 0x00]) => SectionType::ShtNoBits,
-            Some(&[0x09, 0x00, 0x00, 0x00]) => SectionType::ShtRel,
-            Some(&[0x0a, 0x00, 0x00, 0x00]) => SectionType::ShtShlib,
-            Some(&[0x0b, 0x00, 0x00, 0x00]) => SectionType::ShtDynSym,
-            Some(&[0x0e, 0x00, 0x00, 0x00]) => SectionType::ShtInitArray,
-            Some(&[0x0f, 0x00, 0x00, 0x00]) => SectionType::ShtFInitArray,
-            Some(&[0x10, 0x00, 0x00, 0x00]) => SectionType::ShtPreInitArray,
-            Some(&[0x11, 0x00, 0x00, 0x00]) => SectionType::ShtGroup,
-            Some(&[0x12, 0x00, 0x00, 0x00]) => SectionType::ShtSymTabShndx,
-            Some(&[0x13, 0x00, 0x00, 0x00]) => SectionType::ShtRelr,
-            Some(&[0x14, 0x00, 0x00, 0x00]) => SectionType::ShtNum,
-            Some(&[0xf5, 0xff, 0xff, 0x06]) => SectionType::ShtGnuAttributes,
-            Some(&[0xf6, 0xff, 0xff, 0x06]) => SectionType::ShtGnuHash,
-            Some(&[0xf7, 0xff, 0xff, 0x06]) => SectionType::ShtGnuLibList,
-            Some(&[_, _, _, 0x06]) => SectionType::ShtOs,
-            Some(&[_, _, _, 0x07]) => SectionType::ShtProc,
-            Some(&[_, _, _, 0x08]) => SectionType::ShtUser,
-            _ => SectionType::None,
-        };
+        self.sh_type =
+            SectionType::from(u32::endian_parse(0x04..0x08, elf, &data)?);
 
         // Branch and parse according to the elf architecture class
         if class == ElfClass::Class32 {
             // Get the section flags
             self.sh_flags.0 =
-                u32::endian_parse(0x08..0x0c, elf, &data)? as usize;
+                u32::endian_parse(0x08..0x0c, elf, &data)? as u64;
 
             // Get the virtual address of the section in memory
-            self.sh_addr = u32::endian_parse(0x0c..0x10, elf, &data)? as usize;
+            self.sh_addr = u32::endian_parse(0x0c..0x10, elf, &data)? as u64;
 
             // Get the offset of the section in elf file image
-            self.sh_offset =
-                u32::endian_parse(0x10..0x14, elf, &data)? as usize;
+            self.sh_offset = u32::endian_parse(0x10..0x14, elf, &data)? as u64;
 
             // Get the size of the section in the elf file image in bytes
-            self.sh_size = u32::endian_parse(0x14..0x18, elf, &data)? as usize;
+            self.sh_size = u32::endian_parse(0x14..0x18, elf, &data)? as u64;
 
             // Get the section index
             self.sh_link = u32::endian_parse(0x18..0x1c, elf, &data)?;
@@ -235,23 +550,23 @@ impl SectionHeader {
 
             // Get the section alignment
             self.sh_addralign =
-                u32::endian_parse(0x20..0x24, elf, &data)? as usize;
+                u32::endian_parse(0x20..0x24, elf, &data)? as u64;
 
             // Get the entry size in bytes for sections that contain entries
             self.sh_entsize =
-                u32::endian_parse(0x24..0x28, elf, &data)? as usize;
+                u32::endian_parse(0x24..0x28, elf, &data)? as u64;
         } else if class == ElfClass::Class64 {
             // Get the section flags
-            self.sh_flags.0 = usize::endian_parse(0x08..0x10, elf, &data)?;
+            self.sh_flags.0 = u64::endian_parse(0x08..0x10, elf, &data)?;
 
             // Get the virtual address of the section in memory
-            self.sh_addr = usize::endian_parse(0x10..0x18, elf, &data)?;
+            self.sh_addr = u64::endian_parse(0x10..0x18, elf, &data)?;
 
             // Get the offset of the section in elf file image
-            self.sh_offset = usize::endian_parse(0x18..0x20, elf, &data)?;
+            self.sh_offset = u64::endian_parse(0x18..0x20, elf, &data)?;
 
             // Get the size of the section in the elf file image in bytes
-            self.sh_size = usize::endian_parse(0x20..0x28, elf, &data)?;
+            self.sh_size = u64::endian_parse(0x20..0x28, elf, &data)?;
 
             // Get the section index
             self.sh_link = u32::endian_parse(0x28..0x2c, elf, &data)?;
@@ -260,90 +575,351 @@ impl SectionHeader {
             self.sh_info = u32::endian_parse(0x2c..0x30, elf, &data)?;
 
             // Get the section alignment
-            self.sh_addralign = usize::endian_parse(0x30..0x38, elf, &data)?;
+            self.sh_addralign = u64::endian_parse(0x30..0x38, elf, &data)?;
 
             // Get the entry size in bytes for sections that contain entries
-            self.sh_entsize = usize::endian_parse(0x38..0x40, elf, &data)?;
+            self.sh_entsize = u64::endian_parse(0x38..0x40, elf, &data)?;
         }
 
         Ok(self)
     }
+
+    /// Write the section header into `buf` with the layout and the byte
+    /// order of the elf class and data encoding
+    /// This is the inverse of `parse`
+    pub fn emit(
+        &self,
+        buf: &mut [u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<()> {
+        self.sh_name.endian_emit(0x00..0x04, buf, &data)?;
+        self.sh_type.raw_value().endian_emit(0x04..0x08, buf, &data)?;
+
+        // Branch and emit according to the elf architecture class
+        if class == ElfClass::Class32 {
+            narrow(self.sh_flags.0)?.endian_emit(0x08..0x0c, buf, &data)?;
+            narrow(self.sh_addr)?.endian_emit(0x0c..0x10, buf, &data)?;
+            narrow(self.sh_offset)?.endian_emit(0x10..0x14, buf, &data)?;
+            narrow(self.sh_size)?.endian_emit(0x14..0x18, buf, &data)?;
+            self.sh_link.endian_emit(0x18..0x1c, buf, &data)?;
+            self.sh_info.endian_emit(0x1c..0x20, buf, &data)?;
+            narrow(self.sh_addralign)?.endian_emit(0x20..0x24, buf, &data)?;
+            narrow(self.sh_entsize)?.endian_emit(0x24..0x28, buf, &data)?;
+        } else if class == ElfClass::Class64 {
+            self.sh_flags.0.endian_emit(0x08..0x10, buf, &data)?;
+            self.sh_addr.endian_emit(0x10..0x18, buf, &data)?;
+            self.sh_offset.endian_emit(0x18..0x20, buf, &data)?;
+            self.sh_size.endian_emit(0x20..0x28, buf, &data)?;
+            self.sh_link.endian_emit(0x28..0x2c, buf, &data)?;
+            self.sh_info.endian_emit(0x2c..0x30, buf, &data)?;
+            self.sh_addralign.endian_emit(0x30..0x38, buf, &data)?;
+            self.sh_entsize.endian_emit(0x38..0x40, buf, &data)?;
+        } else {
+            return Err(Error::UnsupportedClass);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of entries of a section with fixed size entries
+    /// Sections without an entry size return `None`
+    #[must_use]
+    pub fn entry_count(&self) -> Option<usize> {
+        let count = self.sh_size.checked_div(self.sh_entsize)?;
+        usize::try_from(count).ok()
+    }
+
+    /// Returns true if the section has no data
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sh_size == 0
+    }
+
+    /// Returns true if the file `offset` is inside the section data
+    /// `ShtNoBits` sections have no data in the file
+    #[must_use]
+    pub fn contains_file_offset(&self, offset: u64) -> bool {
+        self.sh_type != SectionType::ShtNoBits
+            && contains(self.sh_offset, self.sh_size, offset)
+    }
+
+    /// Returns true if the data of both sections overlap in the file
+    /// `ShtNoBits` sections have no data in the file
+    #[must_use]
+    pub fn overlaps_file(&self, other: &SectionHeader) -> bool {
+        self.sh_type != SectionType::ShtNoBits
+            && other.sh_type != SectionType::ShtNoBits
+            && overlaps(self.sh_offset, self.sh_size, other.sh_offset,
+                other.sh_size)
+    }
+
+    /// Returns true if the virtual address `addr` is inside the section
+    /// Only sections with the `SHF_ALLOC` flag have a meaningful address
+    #[must_use]
+    pub fn contains_vaddr(&self, addr: u64) -> bool {
+        contains(self.sh_addr, self.sh_size, addr)
+    }
 }
 
 impl SectionFlags {
+    /// Writable
+    pub const WRITE: SectionFlags = SectionFlags(SHF_WRITE as u64);
+    /// Occupies memory during execution
+    pub const ALLOC: SectionFlags = SectionFlags(SHF_ALLOC as u64);
+    /// Executable
+    pub const EXECINSTR: SectionFlags = SectionFlags(SHF_EXECINSTR as u64);
+    /// Might be merged
+    pub const MERGE: SectionFlags = SectionFlags(SHF_MERGE as u64);
+    /// Contains nul-terminated strings
+    pub const STRINGS: SectionFlags = SectionFlags(SHF_STRINGS as u64);
+    /// `sh_info` contains SHT index
+    pub const INFO_LINK: SectionFlags = SectionFlags(SHF_INFO_LINK as u64);
+    /// Preserve order after combining
+    pub const LINK_ORDER: SectionFlags =
+        SectionFlags(SHF_LINK_ORDER as u64);
+    /// Non-standard OS specific handling
+    pub const OS_NONCONFORMING: SectionFlags =
+        SectionFlags(SHF_OS_NONCONFORMING as u64);
+    /// Section is member of a group
+    pub const GROUP: SectionFlags = SectionFlags(SHF_GROUP as u64);
+    /// Section hold thread-local data
+    pub const TLS: SectionFlags = SectionFlags(SHF_TLS as u64);
+    /// Section with compressed data
+    pub const COMPRESSED: SectionFlags =
+        SectionFlags(SHF_COMPRESSED as u64);
+    /// Not to be GCed by linker
+    pub const GNU_RETAIN: SectionFlags =
+        SectionFlags(SHF_GNU_RETAIN as u64);
+    /// Special ordering requirement
+    pub const ORDERED: SectionFlags = SectionFlags(SHF_ORDERED as u64);
+    /// Section is excluded unless referenced or allocated (Solaris)
+    pub const EXCLUDE: SectionFlags = SectionFlags(SHF_EXCLUDE as u64);
+
+    /// Construct the flags without any bit set
+    pub fn empty() -> Self {
+        SectionFlags(0)
+    }
+    /// Construct the flags from the raw `sh_flags` bits
+    pub fn from_bits(bits: u64) -> Self {
+        SectionFlags(bits)
+    }
+    /// Returns the raw `sh_flags` bits
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+    /// Returns true if all the bits of `mask` are set
+    #[must_use]
+    pub fn contains(self, mask: SectionFlags) -> bool {
+        self.0 & mask.0 == mask.0
+    }
+    #[inline]
+    #[must_use]
     pub fn is_write(self) -> bool {
-        self.0 & SHF_WRITE as usize == SHF_WRITE as usize
+        self.0 & SHF_WRITE as u64 == SHF_WRITE as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_alloc(self) -> bool {
-        self.0 & SHF_ALLOC as usize == SHF_ALLOC as usize
+        self.0 & SHF_ALLOC as u64 == SHF_ALLOC as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_exec(self) -> bool {
-        self.0 & SHF_EXECINSTR as usize == SHF_EXECINSTR as usize
+        self.0 & SHF_EXECINSTR as u64 == SHF_EXECINSTR as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_merge(self) -> bool {
-        self.0 & SHF_MERGE as usize == SHF_MERGE as usize
+        self.0 & SHF_MERGE as u64 == SHF_MERGE as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_strings(self) -> bool {
-        self.0 & SHF_STRINGS as usize == SHF_STRINGS as usize
+        self.0 & SHF_STRINGS as u64 == SHF_STRINGS as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_info_link(self) -> bool {
-        self.0 & SHF_INFO_LINK as usize == SHF_INFO_LINK as usize
+        self.0 & SHF_INFO_LINK as u64 == SHF_INFO_LINK as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_link_order(self) -> bool {
-        self.0 & SHF_LINK_ORDER as usize == SHF_LINK_ORDER as usize
+        self.0 & SHF_LINK_ORDER as u64 == SHF_LINK_ORDER as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_os_nonconforming(self) -> bool {
-        self.0 & SHF_OS_NONCONFORMING as usize == SHF_OS_NONCONFORMING as usize
+        self.0 & SHF_OS_NONCONFORMING as u64 == SHF_OS_NONCONFORMING as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_group(self) -> bool {
-        self.0 & SHF_GROUP as usize == SHF_GROUP as usize
+        self.0 & SHF_GROUP as u64 == SHF_GROUP as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_tls(self) -> bool {
-        self.0 & SHF_TLS as usize == SHF_TLS as usize
+        self.0 & SHF_TLS as u64 == SHF_TLS as u64
     }
+    #[inline]
+    #[must_use]
     pub fn is_compressed(self) -> bool {
-        self.0 & SHF_COMPRESSED as usize == SHF_COMPRESSED as usize
+        self.0 & SHF_COMPRESSED as u64 == SHF_COMPRESSED as u64
+    }
+}
+
+impl core::ops::BitOr for SectionFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        SectionFlags(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitAnd for SectionFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        SectionFlags(self.0 & rhs.0)
+    }
+}
+
+impl core::ops::BitXor for SectionFlags {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        SectionFlags(self.0 ^ rhs.0)
+    }
+}
+
+impl core::ops::Not for SectionFlags {
+    type Output = Self;
+    fn not(self) -> Self {
+        SectionFlags(!self.0)
+    }
+}
+
+impl Default for CompressedSectionHeader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompressedSectionHeader {
+    /// The default `CompressedSectionHeader` constructor
+    pub fn new() -> Self {
+        CompressedSectionHeader {
+            ch_type: 0,
+            ch_size: 0,
+            ch_addralign: 0,
+        }
+    }
+
+    /// Parse the compression header and populate the fields
+    pub fn parse(
+        mut self,
+        elf: &[u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<Self> {
+        // Get the compression algorithm
+        self.ch_type = u32::endian_parse(0x00..0x04, elf, &data)?;
+
+        // Branch and parse according to the elf architecture class
+        if class == ElfClass::Class32 {
+            // Get the uncompressed data size
+            self.ch_size = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
+
+            // Get the uncompressed data alignment
+            self.ch_addralign =
+                u32::endian_parse(0x08..0x0c, elf, &data)? as u64;
+        } else if class == ElfClass::Class64 {
+            // Get the uncompressed data size after the reserved word
+            self.ch_size = u64::endian_parse(0x08..0x10, elf, &data)?;
+
+            // Get the uncompressed data alignment
+            self.ch_addralign = u64::endian_parse(0x10..0x18, elf, &data)?;
+        }
+
+        Ok(self)
     }
 }
 
 impl<'a> Iterator for SectionIterator<'a> {
     type Item = SectionHeader;
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         // If the number of section headers is zero then abort the iterator
         if self.shnum == 0 {
             None
         } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let shnum = core::mem::take(&mut self.shnum);
+
             // Parse the section header into the struct
+            let end = self.offset.checked_add(self.shentsize as usize)?;
             self.section_header = self
                 .section_header
-                .parse(
-                    &self.elf
-                        [self.offset..self.offset + self.shentsize as usize],
-                    self.class,
-                    self.data,
-                )
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
                 .ok()?;
 
             // Calculate the next offset for the next program header
-            self.offset += self.shentsize as usize;
+            self.offset = end;
 
             // Subtract one from the number of the program headers
-            self.shnum -= 1;
+            self.shnum = shnum - 1;
 
             // Set the index number
             self.section_header.sh_ndx = self.ndx;
-            self.ndx = self.ndx + 1;
+            self.ndx += 1;
 
             Some(self.section_header)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.shnum, Some(self.shnum))
+    }
+}
+
+impl<'a> ExactSizeIterator for SectionIterator<'a> {}
+
+impl<'a> DoubleEndedIterator for SectionIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // If the number of section headers is zero then abort the iterator
+        if self.shnum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration and the length stays exact
+            let shnum = core::mem::take(&mut self.shnum);
+
+            // The last unvisited entry is found from the front offset
+            let start = (shnum - 1)
+                .checked_mul(self.shentsize as usize)
+                .and_then(|skip| self.offset.checked_add(skip))?;
+            let end = start.checked_add(self.shentsize as usize)?;
+            let mut section_header = SectionHeader::new()
+                .parse(self.elf.get(start..end)?, self.class, self.data)
+                .ok()?;
+
+            // Set the index number
+            section_header.sh_ndx = self.ndx + shnum - 1;
+
+            // Subtract one from the number of the section headers
+            self.shnum = shnum - 1;
+
+            Some(section_header)
+        }
+    }
 }
 
 impl<'a> SectionIterator<'a> {
     pub fn new(
-        e_shoff: usize,
+        e_shoff: u64,
         e_shentsize: u16,
-        e_shnum: u16,
+        e_shnum: usize,
         class: ElfClass,
         data: ElfData,
         elf: &'a [u8],
@@ -351,15 +927,503 @@ impl<'a> SectionIterator<'a> {
         // Construct a empty section header for the program iterator
         let section = SectionHeader::new();
 
+        let offset = saturating_usize(e_shoff);
+        let shnum = checked_table_len(
+            elf,
+            offset,
+            e_shentsize as usize,
+            e_shnum,
+            |ent| section.parse(ent, class, data),
+        );
+
         SectionIterator {
             ndx: 0,
             section_header: section,
-            offset: e_shoff,
+            offset,
             shentsize: e_shentsize,
-            shnum: e_shnum,
+            shnum,
             class,
             data,
             elf,
         }
     }
+
+    /// Returns an iterator over the remaining sections of type `ty`
+    pub fn of_type(
+        self,
+        ty: SectionType,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.filter(move |section| section.sh_type == ty)
+    }
+
+    /// Returns an iterator over the remaining sections whose flags
+    /// contain all the bits of `mask`
+    pub fn of_flags(
+        self,
+        mask: SectionFlags,
+    ) -> impl Iterator<Item = SectionHeader> + 'a {
+        self.filter(move |section| section.sh_flags.contains(mask))
+    }
+}
+
+impl From<u8> for SymType {
+    fn from(value: u8) -> Self {
+        // The symbol type is stored in the low nibble of `st_info`
+        match value & 0x0f {
+            0  => SymType::NoType,
+            1  => SymType::Object,
+            2  => SymType::Func,
+            3  => SymType::Section,
+            4  => SymType::File,
+            5  => SymType::Common,
+            6  => SymType::Tls,
+            10 => SymType::GnuIfunc,
+            12 => SymType::HiOs,
+            13 => SymType::LoProc,
+            15 => SymType::HiProc,
+            ty => SymType::Unknown(ty),
+        }
+    }
+}
+
+impl SymType {
+    /// Returns the raw type stored in the low nibble of `st_info`
+    /// `Unknown` returns the value it holds and `None` returns zero as it
+    /// has no value
+    pub fn raw_value(self) -> u8 {
+        match self {
+            SymType::None     => 0,
+            SymType::NoType   => 0,
+            SymType::Object   => 1,
+            SymType::Func     => 2,
+            SymType::Section  => 3,
+            SymType::File     => 4,
+            SymType::Common   => 5,
+            SymType::Tls      => 6,
+            SymType::GnuIfunc => 10,
+            SymType::LoOs     => 10,
+            SymType::HiOs     => 12,
+            SymType::LoProc   => 13,
+            SymType::HiProc   => 15,
+            SymType::Unknown(ty) => ty,
+        }
+    }
+}
+
+impl From<u8> for SymBinding {
+    fn from(value: u8) -> Self {
+        // The symbol binding is stored in the high nibble of `st_info`
+        match value >> 4 {
+            0  => SymBinding::Local,
+            1  => SymBinding::Global,
+            2  => SymBinding::Weak,
+            10 => SymBinding::LoOs,
+            12 => SymBinding::HiOs,
+            13 => SymBinding::LoProc,
+            15 => SymBinding::HiProc,
+            bind => SymBinding::Unknown(bind),
+        }
+    }
+}
+
+impl SymBinding {
+    /// Returns the raw binding stored in the high nibble of `st_info`
+    /// `Unknown` returns the value it holds and `None` returns zero as it
+    /// has no value
+    pub fn raw_value(self) -> u8 {
+        match self {
+            SymBinding::None   => 0,
+            SymBinding::Local  => 0,
+            SymBinding::Global => 1,
+            SymBinding::Weak   => 2,
+            SymBinding::LoOs   => 10,
+            SymBinding::HiOs   => 12,
+            SymBinding::LoProc => 13,
+            SymBinding::HiProc => 15,
+            SymBinding::Unknown(bind) => bind,
+        }
+    }
+}
+
+impl From<u8> for SymVisibility {
+    fn from(value: u8) -> Self {
+        // The symbol visibility is stored in the low two bits of `st_other`
+        match value & 0x03 {
+            0 => SymVisibility::Default,
+            1 => SymVisibility::Internal,
+            2 => SymVisibility::Hidden,
+            _ => SymVisibility::Protected,
+        }
+    }
+}
+
+impl From<u16> for SectionIndex {
+    fn from(value: u16) -> Self {
+        match value {
+            SHN_UNDEF => SectionIndex::Undef,
+            SHN_ABS => SectionIndex::Abs,
+            SHN_COMMON => SectionIndex::Common,
+            SHN_XINDEX => SectionIndex::Xindex,
+            ndx => SectionIndex::Index(ndx),
+        }
+    }
+}
+
+impl Default for SymTabEnt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymTabEnt {
+    /// The default `SymTabEnt` constructor
+    pub fn new() -> Self {
+        SymTabEnt {
+            st_name: 0,
+            st_info: SymType::None,
+            st_bind: SymBinding::None,
+            st_other: 0,
+            st_shndx: 0,
+            st_value: 0,
+            st_size: 0,
+        }
+    }
+
+    /// Parse the symbol table entry and populate the fields
+    #[inline]
+    pub fn parse(
+        mut self,
+        elf: &[u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<Self> {
+        // Get the pointer to the name of the symbol
+        self.st_name = u32::endian_parse(0x00..0x04, elf, &data)?;
+
+        // Branch and parse according to the elf architecture class
+        if class == ElfClass::Class32 {
+            // Get the value of the symbol
+            self.st_value = u32::endian_parse(0x04..0x08, elf, &data)? as u64;
+
+            // Get the size of the symbol
+            self.st_size = u32::endian_parse(0x08..0x0c, elf, &data)? as u64;
+
+            // Get the symbol type and binding
+            let info = *elf.get(0x0c).ok_or(Error::OffsetCalculationFailure)?;
+            self.st_info = SymType::from(info);
+            self.st_bind = SymBinding::from(info);
+
+            // Get the symbol visibility
+            self.st_other =
+                *elf.get(0x0d).ok_or(Error::OffsetCalculationFailure)?;
+
+            // Get the section index of the symbol
+            self.st_shndx = u16::endian_parse(0x0e..0x10, elf, &data)?;
+        } else if class == ElfClass::Class64 {
+            // Get the symbol type and binding
+            let info = *elf.get(0x04).ok_or(Error::OffsetCalculationFailure)?;
+            self.st_info = SymType::from(info);
+            self.st_bind = SymBinding::from(info);
+
+            // Get the symbol visibility
+            self.st_other =
+                *elf.get(0x05).ok_or(Error::OffsetCalculationFailure)?;
+
+            // Get the section index of the symbol
+            self.st_shndx = u16::endian_parse(0x06..0x08, elf, &data)?;
+
+            // Get the value of the symbol
+            self.st_value = u64::endian_parse(0x08..0x10, elf, &data)?;
+
+            // Get the size of the symbol
+            self.st_size = u64::endian_parse(0x10..0x18, elf, &data)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write the symbol table entry into `buf` with the layout and the byte
+    /// order of the elf class and data encoding
+    /// This is the inverse of `parse`
+    pub fn emit(
+        &self,
+        buf: &mut [u8],
+        class: ElfClass,
+        data: ElfData,
+    ) -> Result<()> {
+        self.st_name.endian_emit(0x00..0x04, buf, &data)?;
+
+        // Pack the symbol type and binding back into `st_info`
+        let info = (self.st_bind.raw_value() << 4)
+            | (self.st_info.raw_value() & 0x0f);
+
+        // Branch and emit according to the elf architecture class
+        let (fields, rest) = if class == ElfClass::Class32 {
+            narrow(self.st_value)?.endian_emit(0x04..0x08, buf, &data)?;
+            narrow(self.st_size)?.endian_emit(0x08..0x0c, buf, &data)?;
+            (0x0c, 0x0e)
+        } else if class == ElfClass::Class64 {
+            self.st_value.endian_emit(0x08..0x10, buf, &data)?;
+            self.st_size.endian_emit(0x10..0x18, buf, &data)?;
+            (0x04, 0x06)
+        } else {
+            return Err(Error::UnsupportedClass);
+        };
+
+        // Write the type, binding, visibility and section index
+        buf.get_mut(fields..rest)
+            .ok_or(Error::OffsetCalculationFailure)?
+            .copy_from_slice(&[info, self.st_other]);
+        self.st_shndx.endian_emit(rest..rest + 0x02, buf, &data)?;
+
+        Ok(())
+    }
+
+    /// Returns the symbol visibility stored in `st_other`
+    pub fn visibility(&self) -> SymVisibility {
+        SymVisibility::from(self.st_other)
+    }
+
+    /// Returns the section index stored in `st_shndx`
+    pub fn section_index(&self) -> SectionIndex {
+        SectionIndex::from(self.st_shndx)
+    }
+
+    /// Returns true if the symbol is defined in a section of this file
+    #[inline]
+    #[must_use]
+    pub fn is_defined(&self) -> bool {
+        self.section_index() != SectionIndex::Undef
+    }
+
+    /// Returns true if the symbol has a global binding
+    #[inline]
+    #[must_use]
+    pub fn is_global(&self) -> bool {
+        self.st_bind == SymBinding::Global
+    }
+
+    /// Returns true if the symbol has a local binding
+    #[inline]
+    #[must_use]
+    pub fn is_local(&self) -> bool {
+        self.st_bind == SymBinding::Local
+    }
+
+    /// Returns true if the symbol has a weak binding
+    #[inline]
+    #[must_use]
+    pub fn is_weak(&self) -> bool {
+        self.st_bind == SymBinding::Weak
+    }
+
+    /// Returns true if the symbol is a function
+    #[inline]
+    #[must_use]
+    pub fn is_function(&self) -> bool {
+        self.st_info == SymType::Func
+    }
+
+    /// Returns true if the symbol is a GNU indirect function
+    #[inline]
+    #[must_use]
+    pub fn is_ifunc(&self) -> bool {
+        self.st_info == SymType::GnuIfunc
+    }
+
+    /// Returns true if the symbol is a data object
+    #[inline]
+    #[must_use]
+    pub fn is_object(&self) -> bool {
+        self.st_info == SymType::Object
+    }
+
+    /// Returns true if the symbol is a thread local storage entity
+    #[inline]
+    #[must_use]
+    pub fn is_tls(&self) -> bool {
+        self.st_info == SymType::Tls
+    }
+}
+
+impl<'a> Iterator for SymTabIterator<'a> {
+    type Item = SymTabEnt;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // If the number of symbols is zero then abort the iterator
+        if self.symnum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let symnum = core::mem::take(&mut self.symnum);
+
+            // Parse the symbol table entry into the struct
+            let end = self.offset.checked_add(self.entsize)?;
+            self.symbol = self
+                .symbol
+                .parse(self.elf.get(self.offset..end)?, self.class, self.data)
+                .ok()?;
+
+            // Calculate the next offset for the next symbol
+            self.offset = end;
+
+            // Subtract one from the number of the symbols
+            self.symnum = symnum - 1;
+
+            Some(self.symbol)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.symnum, Some(self.symnum))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the entries directly since they all have the same size
+        if n >= self.symnum {
+            self.symnum = 0;
+            return None;
+        }
+        self.offset = n
+            .checked_mul(self.entsize)
+            .and_then(|skip| self.offset.checked_add(skip))?;
+        self.symnum -= n;
+
+        self.next()
+    }
+}
+
+impl<'a> ExactSizeIterator for SymTabIterator<'a> {}
+
+impl<'a> SymTabIterator<'a> {
+    pub fn new(
+        sh: SectionHeader,
+        class: ElfClass,
+        data: ElfData,
+        elf: &'a [u8],
+    ) -> Self {
+        // Construct a empty symbol for the symbol table iterator
+        let symbol = SymTabEnt::new();
+
+        // Sections without a fixed entry size can not be iterated
+        let symnum = sh.sh_size.checked_div(sh.sh_entsize).unwrap_or(0);
+        let offset = saturating_usize(sh.sh_offset);
+        let entsize = saturating_usize(sh.sh_entsize);
+        let symnum = checked_table_len(
+            elf,
+            offset,
+            entsize,
+            saturating_usize(symnum),
+            |ent| symbol.parse(ent, class, data),
+        );
+
+        SymTabIterator {
+            symbol,
+            offset,
+            entsize,
+            symnum,
+            class,
+            data,
+            elf,
+        }
+    }
+}
+
+impl<'a> Iterator for StrTabIterator<'a> {
+    type Item = (usize, &'a str);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // An unterminated string at the end is not a valid string
+            let offset = self.pos;
+            let rest = self.data.get(offset..)?;
+            let len = rest.iter().position(|&byte| byte == b'\0')?;
+
+            // Move past the null terminator for the next string
+            self.pos = offset + len + 1;
+
+            // Skip the strings which are not valid UTF-8
+            if let Ok(name) = core::str::from_utf8(&rest[..len]) {
+                return Some((offset, name));
+            }
+        }
+    }
+}
+
+impl<'a> StrTabIterator<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        StrTabIterator { data, pos: 0 }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> SectionNameIndex<'a> {
+    /// The default `SectionNameIndex` constructor
+    pub fn new() -> Self {
+        SectionNameIndex {
+            map: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Add the section named `name` to the index
+    /// The first section with a name is kept like in `find_section`
+    pub fn insert(&mut self, name: &'a str, sh: SectionHeader) {
+        self.map.entry(name).or_insert(sh);
+    }
+
+    /// Returns the section named `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<SectionHeader> {
+        self.map.get(name).copied()
+    }
+
+    /// Returns the number of the indexed section names
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if no section name is indexed
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<'a> Iterator for ModinfoIterator<'a> {
+    type Item = (&'a str, &'a str);
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip the null padding between the aligned entries
+        let (_, entry) = self.strings.find(|(_, entry)| !entry.is_empty())?;
+        Some(entry.split_once('=').unwrap_or((entry, "")))
+    }
+}
+
+impl<'a> ModinfoIterator<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        ModinfoIterator {
+            strings: StrTabIterator::new(data),
+        }
+    }
+}
+
+impl<'a> GnuDebugLink<'a> {
+    /// Parse the null terminated file name and the CRC stored at the next
+    /// 4-byte aligned offset from the `.gnu_debuglink` section data
+    pub fn parse(data: &'a [u8], e_data: ElfData) -> Result<Self> {
+        let len = data
+            .iter()
+            .position(|&byte| byte == b'\0')
+            .ok_or(Error::TruncatedElf)?;
+        let filename =
+            core::str::from_utf8(&data[..len]).map_err(|_err| Error::BadElf)?;
+
+        // The CRC follows the padding after the null terminator
+        let offset = align_up(len + 1, 0x04).ok_or(Error::Overflow)?;
+        let end = offset.checked_add(0x04).ok_or(Error::Overflow)?;
+        let crc = u32::endian_parse(offset..end, data, &e_data)
+            .map_err(|_err| Error::TruncatedElf)?;
+
+        Ok(GnuDebugLink { filename, crc })
+    }
 }
@@ -1,11 +1,102 @@
 use crate::file::ElfData;
 
+/// Round `value` up to the next multiple of `align` which must be a power
+/// of two
+#[must_use]
+pub fn align_up(value: usize, align: usize) -> Option<usize> {
+    Some(value.checked_add(align - 1)? & !(align - 1))
+}
+
+/// Returns the number of `entsize` byte entries of the table at `offset`
+/// which the file holds clamped to `count`
+/// Every entry has the same size so if `parse` fails on the first entry
+/// it fails on all of them and the table is empty which makes the count
+/// exact for the iterators
+pub fn checked_table_len<T>(
+    elf: &[u8],
+    offset: usize,
+    entsize: usize,
+    count: usize,
+    parse: impl Fn(&[u8]) -> crate::Result<T>,
+) -> usize {
+    match elf.get(offset..offset.saturating_add(entsize)) {
+        Some(ent) if parse(ent).is_ok() => {
+            let room = (elf.len() - offset).checked_div(entsize).unwrap_or(0);
+            count.min(room)
+        }
+        _ => 0,
+    }
+}
+
+/// Convert a `u64` file value to `usize` saturating on 32-bit hosts so the
+/// out of range values fail on the slice bounds checks
+pub fn saturating_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+/// Convert a `u64` value to the `u32` of a 32-bit elf field failing on
+/// values which do not fit
+pub fn narrow(value: u64) -> crate::Result<u32> {
+    u32::try_from(value).map_err(|_err| crate::Error::Overflow)
+}
+
+/// Returns the bytes of the null terminated string at `ndx` in the string
+/// table bytes without the null terminator
+#[must_use]
+pub fn bytes_at(strtab: &[u8], ndx: u64) -> Option<&[u8]> {
+    let bytes = strtab.get(usize::try_from(ndx).ok()?..)?;
+    // Parse the bytes until null termination
+    let len = bytes.iter().position(|&byte| byte == b'\0')?;
+    bytes.get(..len)
+}
+
+/// Returns the null terminated string at `ndx` in the string table bytes
+#[must_use]
+pub fn str_at(strtab: &[u8], ndx: u64) -> Option<&str> {
+    core::str::from_utf8(bytes_at(strtab, ndx)?).ok()
+}
+
+/// Returns true if the `inner_size` bytes at `inner` are inside the
+/// `size` bytes at `start` where an overflowing range is never inside
+#[must_use]
+pub fn contains_range(start: u64, size: u64, inner: u64, inner_size: u64)
+    -> bool
+{
+    match (start.checked_add(size), inner.checked_add(inner_size)) {
+        (Some(end), Some(inner_end)) => start <= inner && inner_end <= end,
+        _ => false,
+    }
+}
+
+/// Returns true if `value` is inside the `size` bytes at `start` without
+/// overflowing on ranges that reach the end of the address space
+#[must_use]
+pub fn contains(start: u64, size: u64, value: u64) -> bool {
+    value.checked_sub(start).is_some_and(|offset| offset < size)
+}
+
+/// Returns true if the `size` bytes at `start` and the `other_size` bytes
+/// at `other` share at least one byte
+#[must_use]
+pub fn overlaps(start: u64, size: u64, other: u64, other_size: u64) -> bool {
+    let end = start.saturating_add(size);
+    let other_end = other.saturating_add(other_size);
+    size != 0 && other_size != 0 && start < other_end && other < end
+}
+
 pub trait Integer: Sized {
     fn endian_parse(
         range: core::ops::Range<usize>,
         bytes: &[u8],
         e_data: &ElfData,
     ) -> crate::Result<Self>;
+
+    fn endian_emit(
+        self,
+        range: core::ops::Range<usize>,
+        bytes: &mut [u8],
+        e_data: &ElfData,
+    ) -> crate::Result<()>;
 }
 
 impl Integer for usize {
@@ -25,6 +116,25 @@ impl Integer for usize {
             ElfData::None => usize::from_le_bytes(arr),
         })
     }
+
+    fn endian_emit(
+        self,
+        range: core::ops::Range<usize>,
+        bytes: &mut [u8],
+        e_data: &ElfData,
+    ) -> crate::Result<()> {
+        let arr = match e_data {
+            ElfData::ElfData2Lsb => self.to_le_bytes(),
+            ElfData::ElfData2Msb => self.to_be_bytes(),
+            ElfData::None => self.to_le_bytes(),
+        };
+        bytes
+            .get_mut(range)
+            .filter(|slot| slot.len() == arr.len())
+            .ok_or(crate::Error::OffsetCalculationFailure)?
+            .copy_from_slice(&arr);
+        Ok(())
+    }
 }
 
 impl Integer for u32 {
@@ -44,6 +154,25 @@ impl Integer for u32 {
             ElfData::None => u32::from_le_bytes(arr),
         })
     }
+
+    fn endian_emit(
+        self,
+        range: core::ops::Range<usize>,
+        bytes: &mut [u8],
+        e_data: &ElfData,
+    ) -> crate::Result<()> {
+        let arr = match e_data {
+            ElfData::ElfData2Lsb => self.to_le_bytes(),
+            ElfData::ElfData2Msb => self.to_be_bytes(),
+            ElfData::None => self.to_le_bytes(),
+        };
+        bytes
+            .get_mut(range)
+            .filter(|slot| slot.len() == arr.len())
+            .ok_or(crate::Error::OffsetCalculationFailure)?
+            .copy_from_slice(&arr);
+        Ok(())
+    }
 }
 
 impl Integer for u16 {
@@ -63,4 +192,61 @@ impl Integer for u16 {
             ElfData::None => u16::from_le_bytes(arr),
         })
     }
+
+    fn endian_emit(
+        self,
+        range: core::ops::Range<usize>,
+        bytes: &mut [u8],
+        e_data: &ElfData,
+    ) -> crate::Result<()> {
+        let arr = match e_data {
+            ElfData::ElfData2Lsb => self.to_le_bytes(),
+            ElfData::ElfData2Msb => self.to_be_bytes(),
+            ElfData::None => self.to_le_bytes(),
+        };
+        bytes
+            .get_mut(range)
+            .filter(|slot| slot.len() == arr.len())
+            .ok_or(crate::Error::OffsetCalculationFailure)?
+            .copy_from_slice(&arr);
+        Ok(())
+    }
+}
+
+impl Integer for u64 {
+    fn endian_parse(
+        range: core::ops::Range<usize>,
+        bytes: &[u8],
+        e_data: &ElfData,
+    ) -> crate::Result<Self> {
+        let arr = bytes
+            .get(range)
+            .ok_or(crate::Error::OffsetCalculationFailure)?
+            .try_into()
+            .map_err(|_err| crate::Error::OffsetCalculationFailure)?;
+        Ok(match e_data {
+            ElfData::ElfData2Lsb => u64::from_le_bytes(arr),
+            ElfData::ElfData2Msb => u64::from_be_bytes(arr),
+            ElfData::None => u64::from_le_bytes(arr),
+        })
+    }
+
+    fn endian_emit(
+        self,
+        range: core::ops::Range<usize>,
+        bytes: &mut [u8],
+        e_data: &ElfData,
+    ) -> crate::Result<()> {
+        let arr = match e_data {
+            ElfData::ElfData2Lsb => self.to_le_bytes(),
+            ElfData::ElfData2Msb => self.to_be_bytes(),
+            ElfData::None => self.to_le_bytes(),
+        };
+        bytes
+            .get_mut(range)
+            .filter(|slot| slot.len() == arr.len())
+            .ok_or(crate::Error::OffsetCalculationFailure)?
+            .copy_from_slice(&arr);
+        Ok(())
+    }
 }
@@ -0,0 +1,428 @@
+use crate::utils::{checked_table_len, saturating_usize, Integer};
+use crate::file::ElfData;
+use crate::section::SectionHeader;
+
+/// Version index of the local symbols
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Version index of the global symbols without a version
+pub const VER_NDX_GLOBAL: u16 = 1;
+/// Bit of the version index marking a hidden symbol version
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+
+/// Version definition of the file itself
+pub const VER_FLG_BASE: u16 = 0x1;
+/// Weak version definition or dependency
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+/// Symbol version entry stored in the `ShtGnuVerSym` section
+/// Every entry belongs to the dynamic symbol at the same index
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VersionSym(pub u16);
+
+/// Version dependency entry stored in the `ShtGnuVerNeed` section
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionNeedEnt<'a> {
+    /// Version of the structure which is 1
+    pub vn_version: u16,
+    /// Number of the auxiliary entries
+    pub vn_cnt: u16,
+    /// String table offset of the needed file name
+    pub vn_file: u32,
+    /// Offset of the first auxiliary entry from this entry
+    pub vn_aux: u32,
+    /// Offset of the next entry from this entry
+    pub vn_next: u32,
+    /// Auxiliary entries of the needed file
+    aux: VersionNeedAuxIterator<'a>,
+}
+
+/// Auxiliary version dependency entry naming a needed version
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionNeedAux {
+    /// Hash value of the version name
+    pub vna_hash: u32,
+    /// Version flags like `VER_FLG_WEAK`
+    pub vna_flags: u16,
+    /// Version index used in the `ShtGnuVerSym` section
+    pub vna_other: u16,
+    /// String table offset of the version name
+    pub vna_name: u32,
+    /// Offset of the next auxiliary entry from this entry
+    pub vna_next: u32,
+}
+
+/// Version definition entry stored in the `ShtGnuVerDef` section
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionDefEnt<'a> {
+    /// Version of the structure which is 1
+    pub vd_version: u16,
+    /// Version flags like `VER_FLG_BASE`
+    pub vd_flags: u16,
+    /// Version index used in the `ShtGnuVerSym` section
+    pub vd_ndx: u16,
+    /// Number of the auxiliary entries
+    pub vd_cnt: u16,
+    /// Hash value of the version name
+    pub vd_hash: u32,
+    /// Offset of the first auxiliary entry from this entry
+    pub vd_aux: u32,
+    /// Offset of the next entry from this entry
+    pub vd_next: u32,
+    /// Auxiliary entries of the definition
+    aux: VersionDefAuxIterator<'a>,
+}
+
+/// Auxiliary version definition entry naming the version and its parents
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionDefAux {
+    /// String table offset of the version or parent version name
+    pub vda_name: u32,
+    /// Offset of the next auxiliary entry from this entry
+    pub vda_next: u32,
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `versym_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct VersionSymIterator<'a> {
+    /// Symbol version table offset in the elf file
+    offset: usize,
+    /// Number of symbol version entries also used as
+    /// the index of the iteration
+    versymnum: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `verneed_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct VersionNeedIterator<'a> {
+    /// Offset of the next entry in the elf file
+    offset: usize,
+    /// End offset of the section in the elf file
+    end: usize,
+    /// Number of the remaining entries
+    verneednum: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `aux_iter()` function to be called
+/// on the version dependency entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionNeedAuxIterator<'a> {
+    /// Offset of the next auxiliary entry in the elf file
+    offset: usize,
+    /// End offset of the section in the elf file
+    end: usize,
+    /// Number of the remaining auxiliary entries
+    auxnum: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `verdef_iter()` function to be called
+/// on the elf struct
+#[derive(Debug, Clone, Copy)]
+pub struct VersionDefIterator<'a> {
+    /// Offset of the next entry in the elf file
+    offset: usize,
+    /// End offset of the section in the elf file
+    end: usize,
+    /// Number of the remaining entries
+    verdefnum: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+/// Helper type to implement the iterator type on
+/// The best is for the `aux_iter()` function to be called
+/// on the version definition entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionDefAuxIterator<'a> {
+    /// Offset of the next auxiliary entry in the elf file
+    offset: usize,
+    /// End offset of the section in the elf file
+    end: usize,
+    /// Number of the remaining auxiliary entries
+    auxnum: usize,
+    /// Elf endianness used for parsing
+    data: ElfData,
+    /// A reference to the elf file
+    elf: &'a [u8],
+}
+
+impl VersionSym {
+    /// Returns true if the symbol version is hidden and the symbol can only
+    /// be found when its version is explicitly requested
+    #[inline]
+    #[must_use]
+    pub fn is_hidden(&self) -> bool {
+        self.0 & VERSYM_HIDDEN != 0
+    }
+
+    /// Returns the version index without the hidden bit
+    /// It matches the `vd_ndx` or `vna_other` of the version
+    pub fn version_index(&self) -> u16 {
+        self.0 & !VERSYM_HIDDEN
+    }
+}
+
+impl<'a> VersionNeedEnt<'a> {
+    /// Returns the `VersionNeedAuxIterator` over the needed versions
+    #[must_use]
+    pub fn aux_iter(&self) -> VersionNeedAuxIterator<'a> {
+        self.aux
+    }
+}
+
+impl<'a> VersionDefEnt<'a> {
+    /// Returns the `VersionDefAuxIterator` over the version name and the
+    /// names of its parents
+    #[must_use]
+    pub fn aux_iter(&self) -> VersionDefAuxIterator<'a> {
+        self.aux
+    }
+}
+
+impl<'a> Iterator for VersionSymIterator<'a> {
+    type Item = VersionSym;
+    fn next(&mut self) -> Option<Self::Item> {
+        // If the number of entries is zero then abort the iterator
+        if self.versymnum == 0 {
+            None
+        } else {
+            // Take the remaining count so a malformed entry ends the
+            // iteration
+            let versymnum = core::mem::take(&mut self.versymnum);
+
+            // Parse the version index of the symbol
+            let end = self.offset.checked_add(0x02)?;
+            let versym =
+                u16::endian_parse(self.offset..end, self.elf, &self.data)
+                    .ok()?;
+
+            // Calculate the next offset for the next entry
+            self.offset = end;
+
+            // Subtract one from the number of the entries
+            self.versymnum = versymnum - 1;
+
+            Some(VersionSym(versym))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.versymnum, Some(self.versymnum))
+    }
+}
+
+impl<'a> ExactSizeIterator for VersionSymIterator<'a> {}
+
+impl<'a> Iterator for VersionNeedIterator<'a> {
+    type Item = VersionNeedEnt<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Take the remaining count so a malformed entry ends the iteration
+        let verneednum = core::mem::take(&mut self.verneednum);
+        if verneednum == 0 {
+            return None;
+        }
+
+        // Parse the version dependency entry
+        let ent = self.elf.get(self.offset..self.end)?;
+        let vn_version = u16::endian_parse(0x00..0x02, ent, &self.data).ok()?;
+        let vn_cnt = u16::endian_parse(0x02..0x04, ent, &self.data).ok()?;
+        let vn_file = u32::endian_parse(0x04..0x08, ent, &self.data).ok()?;
+        let vn_aux = u32::endian_parse(0x08..0x0c, ent, &self.data).ok()?;
+        let vn_next = u32::endian_parse(0x0c..0x10, ent, &self.data).ok()?;
+
+        // The auxiliary entries are relative to this entry
+        let aux = VersionNeedAuxIterator {
+            offset: self.offset.checked_add(vn_aux as usize)?,
+            end: self.end,
+            auxnum: vn_cnt as usize,
+            data: self.data,
+            elf: self.elf,
+        };
+
+        // The last entry has no next entry
+        if vn_next != 0 {
+            self.offset = self.offset.checked_add(vn_next as usize)?;
+            self.verneednum = verneednum - 1;
+        }
+
+        Some(VersionNeedEnt {
+            vn_version,
+            vn_cnt,
+            vn_file,
+            vn_aux,
+            vn_next,
+            aux,
+        })
+    }
+}
+
+impl<'a> Iterator for VersionNeedAuxIterator<'a> {
+    type Item = VersionNeedAux;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Take the remaining count so a malformed entry ends the iteration
+        let auxnum = core::mem::take(&mut self.auxnum);
+        if auxnum == 0 {
+            return None;
+        }
+
+        // Parse the auxiliary version dependency entry
+        let aux = self.elf.get(self.offset..self.end)?;
+        let vna_hash = u32::endian_parse(0x00..0x04, aux, &self.data).ok()?;
+        let vna_flags = u16::endian_parse(0x04..0x06, aux, &self.data).ok()?;
+        let vna_other = u16::endian_parse(0x06..0x08, aux, &self.data).ok()?;
+        let vna_name = u32::endian_parse(0x08..0x0c, aux, &self.data).ok()?;
+        let vna_next = u32::endian_parse(0x0c..0x10, aux, &self.data).ok()?;
+
+        // The last auxiliary entry has no next entry
+        if vna_next != 0 {
+            self.offset = self.offset.checked_add(vna_next as usize)?;
+            self.auxnum = auxnum - 1;
+        }
+
+        Some(VersionNeedAux {
+            vna_hash,
+            vna_flags,
+            vna_other,
+            vna_name,
+            vna_next,
+        })
+    }
+}
+
+impl<'a> Iterator for VersionDefIterator<'a> {
+    type Item = VersionDefEnt<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Take the remaining count so a malformed entry ends the iteration
+        let verdefnum = core::mem::take(&mut self.verdefnum);
+        if verdefnum == 0 {
+            return None;
+        }
+
+        // Parse the version definition entry
+        let ent = self.elf.get(self.offset..self.end)?;
+        let vd_version = u16::endian_parse(0x00..0x02, ent, &self.data).ok()?;
+        let vd_flags = u16::endian_parse(0x02..0x04, ent, &self.data).ok()?;
+        let vd_ndx = u16::endian_parse(0x04..0x06, ent, &self.data).ok()?;
+        let vd_cnt = u16::endian_parse(0x06..0x08, ent, &self.data).ok()?;
+        let vd_hash = u32::endian_parse(0x08..0x0c, ent, &self.data).ok()?;
+        let vd_aux = u32::endian_parse(0x0c..0x10, ent, &self.data).ok()?;
+        let vd_next = u32::endian_parse(0x10..0x14, ent, &self.data).ok()?;
+
+        // The auxiliary entries are relative to this entry
+        let aux = VersionDefAuxIterator {
+            offset: self.offset.checked_add(vd_aux as usize)?,
+            end: self.end,
+            auxnum: vd_cnt as usize,
+            data: self.data,
+            elf: self.elf,
+        };
+
+        // The last entry has no next entry
+        if vd_next != 0 {
+            self.offset = self.offset.checked_add(vd_next as usize)?;
+            self.verdefnum = verdefnum - 1;
+        }
+
+        Some(VersionDefEnt {
+            vd_version,
+            vd_flags,
+            vd_ndx,
+            vd_cnt,
+            vd_hash,
+            vd_aux,
+            vd_next,
+            aux,
+        })
+    }
+}
+
+impl<'a> Iterator for VersionDefAuxIterator<'a> {
+    type Item = VersionDefAux;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Take the remaining count so a malformed entry ends the iteration
+        let auxnum = core::mem::take(&mut self.auxnum);
+        if auxnum == 0 {
+            return None;
+        }
+
+        // Parse the auxiliary version definition entry
+        let aux = self.elf.get(self.offset..self.end)?;
+        let vda_name = u32::endian_parse(0x00..0x04, aux, &self.data).ok()?;
+        let vda_next = u32::endian_parse(0x04..0x08, aux, &self.data).ok()?;
+
+        // The last auxiliary entry has no next entry
+        if vda_next != 0 {
+            self.offset = self.offset.checked_add(vda_next as usize)?;
+            self.auxnum = auxnum - 1;
+        }
+
+        Some(VersionDefAux { vda_name, vda_next })
+    }
+}
+
+impl<'a> VersionSymIterator<'a> {
+    pub fn new(sh: SectionHeader, data: ElfData, elf: &'a [u8]) -> Self {
+        let offset = saturating_usize(sh.sh_offset);
+
+        // Every entry is a 2 byte version index
+        let versymnum = checked_table_len(
+            elf,
+            offset,
+            0x02,
+            saturating_usize(sh.sh_size / 0x02),
+            |ent| u16::endian_parse(0..0x02, ent, &data),
+        );
+
+        VersionSymIterator {
+            offset,
+            versymnum,
+            data,
+            elf,
+        }
+    }
+}
+
+impl<'a> VersionNeedIterator<'a> {
+    pub fn new(sh: SectionHeader, data: ElfData, elf: &'a [u8]) -> Self {
+        VersionNeedIterator {
+            offset: saturating_usize(sh.sh_offset),
+            end: saturating_usize(sh.sh_offset.saturating_add(sh.sh_size)),
+            // The number of entries is stored in the `sh_info`
+            verneednum: sh.sh_info as usize,
+            data,
+            elf,
+        }
+    }
+}
+
+impl<'a> VersionDefIterator<'a> {
+    pub fn new(sh: SectionHeader, data: ElfData, elf: &'a [u8]) -> Self {
+        VersionDefIterator {
+            offset: saturating_usize(sh.sh_offset),
+            end: saturating_usize(sh.sh_offset.saturating_add(sh.sh_size)),
+            // The number of entries is stored in the `sh_info`
+            verdefnum: sh.sh_info as usize,
+            data,
+            elf,
+        }
+    }
+}
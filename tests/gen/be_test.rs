@@ -0,0 +1,44 @@
+//! Source of the big endian test files built by `build_be.sh`
+//! There is no core library for the targets so the few lang items needed
+//! are defined here
+#![feature(no_core, lang_items)]
+#![allow(internal_features)]
+#![no_core]
+#![no_main]
+
+#[lang = "pointee_sized"]
+pub trait PointeeSized {}
+#[lang = "meta_sized"]
+pub trait MetaSized: PointeeSized {}
+#[lang = "sized"]
+pub trait Sized: MetaSized {}
+#[lang = "copy"]
+pub trait Copy {}
+#[lang = "drop_glue"]
+pub unsafe fn drop_in_place<T: PointeeSized>(_: *mut T) {}
+#[lang = "sync"]
+pub unsafe trait Sync {}
+unsafe impl Sync for u32 {}
+unsafe impl Sync for [u8; 4] {}
+
+/// Read-only data in `.rodata`
+#[no_mangle]
+pub static MESSAGE: [u8; 4] = [1, 2, 3, 4];
+
+/// Initialized data in `.data` whose bytes show the byte order
+#[no_mangle]
+pub static mut COUNTER: u32 = 0x11223344;
+
+/// Zero initialized data in `.bss`
+#[no_mangle]
+pub static mut ZEROED: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn helper(value: u32) -> u32 {
+    value
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
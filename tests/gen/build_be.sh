@@ -0,0 +1,26 @@
+#!/bin/sh
+# Build the big endian test files from be_test.rs
+# The PowerPC targets have no core library so a nightly compiler is needed
+# for the no_core source and the linking is done with the bundled rust-lld
+# The nightly is pinned as the files are checked byte for byte in CI and a
+# newer compiler might generate different code
+set -eu
+
+toolchain=nightly-2026-05-20
+
+cd "$(dirname "$0")"
+out=$(mktemp -d)
+trap 'rm -rf "$out"' EXIT
+
+sysroot=$(rustc +$toolchain --print sysroot)
+host=$(rustc +$toolchain -vV | sed -n 's/^host: //p')
+lld="$sysroot/lib/rustlib/$host/bin/rust-lld"
+
+build() {
+    rustc +$toolchain --target "$1" --crate-type lib --emit obj \
+        -C panic=abort -C opt-level=1 be_test.rs -o "$out/$2.o"
+    "$lld" -flavor gnu -static -e _start -o "../$2" "$out/$2.o"
+}
+
+build powerpc-unknown-linux-gnu elf_be32
+build powerpc64-unknown-linux-gnu elf_be64